@@ -1,26 +1,229 @@
 #![allow(missing_docs)]
 #![allow(non_snake_case)]
 #![allow(unused)]
+// Idiomatic [ERROR] handling.
+use anyhow::{Error, Result};
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+use tokio::sync::OnceCell;
+
 // [render_cdk] modules.
-use crate::{environment_management::prelude::*, state_management::state::Owner};
+use crate::{
+    environment_management::prelude::*,
+    state_management::state::{Owner, State},
+};
+
+// [DEBUG] utils.
+use crate::logger::prelude::*;
+use crate::LOGGER;
+use colored::Colorize;
 
 #[derive(Debug, Clone)]
 pub struct Info {
     pub OWNER_ID: String,
 }
 
+/// Identifies a single owner (user account or team workspace).
+///
+/// Users that belong to several teams share the same email across
+/// owners, so an explicit [OwnerSelector::Id] or [OwnerSelector::Name]
+/// should be preferred over [OwnerSelector::Email].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OwnerSelector {
+    Id(String),
+    Name(String),
+    Email(String),
+}
+
+impl OwnerSelector {
+    fn matches(&self, owner: &Owner) -> bool {
+        match self {
+            OwnerSelector::Id(id) => owner.id == *id,
+            OwnerSelector::Name(name) => owner.name == *name,
+            OwnerSelector::Email(email) => owner.email == *email,
+        }
+    }
+}
+
+/// Resolved owners, keyed by the API and [API_KEY] they were resolved with.
+/// Concurrent callers share the cell of their key, so a single lookup runs.
+static OWNER_CACHE: OnceLock<RwLock<HashMap<String, Arc<OnceCell<Owner>>>>> = OnceLock::new();
+
+fn owner_cache() -> &'static RwLock<HashMap<String, Arc<OnceCell<Owner>>>> {
+    OWNER_CACHE.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
 impl Info {
+    /// Default owner resolution, based on [OWNER_CREDENTIALS].
+    ///
+    /// The owner is resolved once per [API_KEY] and cached, see
+    /// [Info::refresh_owner] to force another lookup.
     pub async fn get_owner_id() -> String {
-        let owner_credentials = EnvironmentManager::retrieve_env_config().OWNER_CREDENTIALS;
-        let authorized_users = Owner::list_authorized_users(&owner_credentials, "100")
+        Info::whoami()
             .await
-            .unwrap();
+            .map(|owner| owner.id)
+            .expect("No authorized users found.")
+    }
 
-        let owner_id = authorized_users
-            .first()
-            .map(|owner_response| owner_response.owner.id.clone())
-            .expect("No authorized users found.");
+    /// Returns the owner deployments are made under, resolving it on first use.
+    pub async fn whoami() -> Result<Owner, Error> {
+        let cache_key = format!("{}|{}", State::base_url(), EnvironmentManager::api_key()?);
 
-        owner_id
+        let cell = owner_cache()
+            .write()
+            .unwrap()
+            .entry(cache_key)
+            .or_default()
+            .clone();
+
+        // A failed lookup leaves the cell empty, the next call tries again.
+        cell.get_or_try_init(Info::resolve_default_owner)
+            .await
+            .cloned()
+    }
+
+    /// Drops the cached owner and resolves it again from [OWNER_CREDENTIALS].
+    pub async fn refresh_owner() -> Result<Owner, Error> {
+        let cache_key = format!("{}|{}", State::base_url(), EnvironmentManager::api_key()?);
+        owner_cache().write().unwrap().remove(&cache_key);
+
+        Info::whoami().await
+    }
+
+    /// Selects the owner used by subsequent deployments, replacing the cached one.
+    pub async fn use_owner(selector: &OwnerSelector) -> Result<Owner, Error> {
+        let cache_key = format!("{}|{}", State::base_url(), EnvironmentManager::api_key()?);
+        let owner = Info::find_owner(selector).await?;
+        owner_cache()
+            .write()
+            .unwrap()
+            .insert(cache_key, Arc::new(OnceCell::from(owner.clone())));
+
+        Ok(owner)
+    }
+
+    /// When the email matches several owners, the first one is used and a
+    /// warning is logged. Use [Info::use_owner] to pick one explicitly.
+    async fn resolve_default_owner() -> Result<Owner, Error> {
+        let owner_credentials = EnvironmentManager::owner_credentials()?;
+        let authorized_users = Owner::list_authorized_users(&owner_credentials, "100").await?;
+
+        if authorized_users.len() > 1 {
+            LOGGER!(
+                "[OWNER] :: Multiple owners match <OWNER_CREDENTIALS>, using the first. -> ",
+                format!(
+                    "{:?}",
+                    authorized_users
+                        .iter()
+                        .map(|owner_response| owner_response.owner.name.as_str())
+                        .collect::<Vec<_>>()
+                ),
+                LogLevel::WARN
+            );
+        }
+
+        authorized_users
+            .into_iter()
+            .next()
+            .map(|owner_response| owner_response.owner)
+            .ok_or_else(|| anyhow::anyhow!("No authorized users found."))
+    }
+
+    /// Lists all owners/workspaces the [API_KEY] has access to.
+    pub async fn list_workspaces() -> Result<Vec<Owner>, Error> {
+        let owners = Owner::list_owners("100").await?;
+
+        Ok(owners
+            .into_iter()
+            .map(|owner_response| owner_response.owner)
+            .collect())
+    }
+
+    /// Resolves exactly one owner matching the selector.
+    pub async fn find_owner(selector: &OwnerSelector) -> Result<Owner, Error> {
+        let workspaces = Info::list_workspaces().await?;
+        Info::select_owner(workspaces, selector)
+    }
+
+    fn select_owner(workspaces: Vec<Owner>, selector: &OwnerSelector) -> Result<Owner, Error> {
+        let mut matches: Vec<Owner> = workspaces
+            .into_iter()
+            .filter(|owner| selector.matches(owner))
+            .collect();
+
+        match matches.len() {
+            0 => Err(anyhow::anyhow!("No owner found matching {:?}.", selector)),
+            1 => Ok(matches.remove(0)),
+            _ => Err(anyhow::anyhow!(
+                "{} owners match {:?}, select one by id instead: {:?}",
+                matches.len(),
+                selector,
+                matches
+                    .iter()
+                    .map(|owner| owner.id.as_str())
+                    .collect::<Vec<_>>()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod owner_tests {
+    use super::*;
+    use crate::testing::prelude::*;
+
+    fn workspace(id: &str, name: &str, type_: &str) -> Owner {
+        Owner {
+            id: id.to_owned(),
+            name: name.to_owned(),
+            email: "dev@example.com".to_owned(),
+            twoFactorAuthEnabled: Some(false),
+            type_: type_.to_owned(),
+        }
+    }
+
+    fn workspaces() -> Vec<Owner> {
+        vec![
+            workspace("usr-a1b2c3d4", "dev", "user"),
+            workspace("tea-e5f6g7h8", "platform", "team"),
+            workspace("tea-i9j0k1l2", "data", "team"),
+        ]
+    }
+
+    #[test]
+    fn test_select_owner_by_id_and_name() {
+        let by_id = Info::select_owner(workspaces(), &OwnerSelector::Id("tea-i9j0k1l2".to_owned()));
+        assert_eq!(by_id.unwrap().name, "data");
+
+        let by_name = Info::select_owner(workspaces(), &OwnerSelector::Name("platform".to_owned()));
+        assert_eq!(by_name.unwrap().id, "tea-e5f6g7h8");
+    }
+
+    #[test]
+    fn test_select_owner_rejects_ambiguous_and_missing() {
+        // Every workspace shares the same email.
+        let ambiguous = Info::select_owner(
+            workspaces(),
+            &OwnerSelector::Email("dev@example.com".to_owned()),
+        );
+        assert!(ambiguous.is_err());
+
+        let missing = Info::select_owner(workspaces(), &OwnerSelector::Name("ops".to_owned()));
+        assert!(missing.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_whoami_resolves_default_owner() {
+        shared();
+
+        // The personal account is listed before the team sharing its email.
+        let owner = Info::whoami().await.unwrap();
+        assert_eq!(owner.id, MOCK_OWNER_ID);
+
+        let team = Info::find_owner(&OwnerSelector::Name("platform".to_owned()))
+            .await
+            .unwrap();
+        assert_eq!(team.id, MOCK_TEAM_ID);
     }
 }
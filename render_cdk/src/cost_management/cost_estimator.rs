@@ -0,0 +1,344 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+// [JSON] parsing.
+use serde::{Deserialize, Serialize};
+
+// Idiomatic [ERROR] handling.
+use anyhow::{Context, Error};
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::sync::{Arc, OnceLock, RwLock};
+
+// [render_cdk] modules.
+use crate::resource_management::models::enums::ServiceType;
+use crate::resource_management::models::template::Template;
+use crate::utils::config::Conf;
+
+/// Prices bundled with the crate, see [./prices.toml].
+const BUNDLED_PRICES: &str = include_str!("prices.toml");
+
+/// The plan Render uses when a service doesn't specify one.
+const DEFAULT_SERVICE_PLAN: &str = "starter";
+
+/// Monthly prices per plan.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct PriceTable {
+    pub currency: String,
+    #[serde(default)]
+    pub services: BTreeMap<String, f64>,
+    #[serde(default)]
+    pub postgres: BTreeMap<String, f64>,
+    #[serde(default)]
+    pub redis: BTreeMap<String, f64>,
+}
+
+impl Default for PriceTable {
+    fn default() -> Self {
+        toml::from_str(BUNDLED_PRICES).expect("The bundled price table is invalid.")
+    }
+}
+
+impl PriceTable {
+    /// Reads a price table, any plan it doesn't list keeps its bundled price.
+    pub fn from_file(path: &str) -> Result<Self, Error> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Unable to READ price table: {:?}", path))?;
+
+        Self::default().merge(&contents)
+    }
+
+    /// Overrides prices with the ones listed in a TOML price table.
+    pub fn merge(mut self, contents: &str) -> Result<Self, Error> {
+        #[derive(Deserialize)]
+        struct Overrides {
+            currency: Option<String>,
+            #[serde(default)]
+            services: BTreeMap<String, f64>,
+            #[serde(default)]
+            postgres: BTreeMap<String, f64>,
+            #[serde(default)]
+            redis: BTreeMap<String, f64>,
+        }
+
+        let overrides: Overrides =
+            toml::from_str(contents).context("Unable to PARSE price table.")?;
+
+        if let Some(currency) = overrides.currency {
+            self.currency = currency;
+        }
+        self.services.extend(overrides.services);
+        self.postgres.extend(overrides.postgres);
+        self.redis.extend(overrides.redis);
+
+        Ok(self)
+    }
+
+    pub fn estimate(&self, config: &Conf, templates: &[Template]) -> CostEstimate {
+        let mut items = vec![];
+
+        if let Some(database) = &config.database {
+            let high_availability = database.enableHighAvailability;
+            let mut item = CostItem::new(
+                "postgres",
+                database.name.as_deref(),
+                &database.plan,
+                if high_availability { 2 } else { 1 },
+                self.postgres.get(&database.plan).copied(),
+            );
+            if high_availability {
+                item.notes.push("high availability standby".to_owned());
+            }
+            items.push(item);
+        }
+
+        if let Some(redis) = &config.redis {
+            items.push(CostItem::new(
+                "redis",
+                redis.name.as_deref(),
+                &redis.plan,
+                1,
+                self.redis.get(&redis.plan).copied(),
+            ));
+        }
+
+        for template in templates {
+            items.push(self.estimate_service(template));
+        }
+
+        CostEstimate {
+            currency: self.currency.clone(),
+            items,
+        }
+    }
+
+    fn estimate_service(&self, template: &Template) -> CostItem {
+        let details = template.service_details.as_ref();
+
+        // Static sites are free.
+        if template.type_ == ServiceType::StaticSite {
+            return CostItem::new("static_site", Some(&template.name), "free", 1, Some(0.0));
+        }
+
+        let plan = details
+            .and_then(|details| details.plan.as_ref())
+            .map(|plan| plan.as_str())
+            .unwrap_or(DEFAULT_SERVICE_PLAN);
+
+        // Autoscaled services are priced at their maximum instance count.
+        let (instances, note) = match &template.autoscaling {
+            Some(autoscaling) => (
+                autoscaling.max.max(1),
+                Some(format!("autoscaling up to {} instances", autoscaling.max)),
+            ),
+            None => (
+                details
+                    .and_then(|details| details.num_instances)
+                    .unwrap_or(1)
+                    .max(1) as u32,
+                None,
+            ),
+        };
+
+        let mut item = CostItem::new(
+            template.type_.as_str(),
+            Some(&template.name),
+            plan,
+            instances,
+            self.services.get(plan).copied(),
+        );
+        item.notes.extend(note);
+        item
+    }
+}
+
+/// The estimated monthly cost of a single resource.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CostItem {
+    pub kind: String,
+    pub name: String,
+    pub plan: String,
+    pub instances: u32,
+    /// `None` when the plan isn't listed in the [PriceTable].
+    pub unit_price: Option<f64>,
+    pub monthly: f64,
+    pub notes: Vec<String>,
+}
+
+impl CostItem {
+    fn new(
+        kind: &str,
+        name: Option<&str>,
+        plan: &str,
+        instances: u32,
+        unit_price: Option<f64>,
+    ) -> Self {
+        let mut notes = vec![];
+        if unit_price.is_none() {
+            notes.push("unknown plan, not included in the total".to_owned());
+        }
+
+        Self {
+            kind: kind.to_owned(),
+            name: match name {
+                Some(name) if !name.is_empty() => name.to_owned(),
+                _ => "<generated>".to_owned(),
+            },
+            plan: plan.to_owned(),
+            instances,
+            unit_price,
+            monthly: unit_price.unwrap_or(0.0) * instances as f64,
+            notes,
+        }
+    }
+}
+
+/// A monthly cost breakdown per resource.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct CostEstimate {
+    pub currency: String,
+    pub items: Vec<CostItem>,
+}
+
+impl CostEstimate {
+    pub fn total(&self) -> f64 {
+        self.items.iter().map(|item| item.monthly).sum()
+    }
+}
+
+impl fmt::Display for CostEstimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Estimated monthly cost ({}):", self.currency)?;
+        for item in &self.items {
+            write!(
+                f,
+                "  {:<18} {:<24} {:<16} x{:<3} {:>10.2}",
+                item.kind, item.name, item.plan, item.instances, item.monthly
+            )?;
+            if !item.notes.is_empty() {
+                write!(f, "  ({})", item.notes.join(", "))?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "  {:<65} {:>10.2}", "Total", self.total())
+    }
+}
+
+static PRICES: OnceLock<RwLock<Arc<PriceTable>>> = OnceLock::new();
+
+fn prices() -> &'static RwLock<Arc<PriceTable>> {
+    PRICES.get_or_init(|| RwLock::new(Arc::new(PriceTable::default())))
+}
+
+/// The process wide [PriceTable] estimates are based on.
+#[derive(Debug)]
+pub struct CostEstimator;
+
+impl CostEstimator {
+    /// Replaces the price table, for the whole process.
+    pub fn configure(table: PriceTable) {
+        *prices().write().unwrap() = Arc::new(table);
+    }
+
+    pub fn current() -> Arc<PriceTable> {
+        prices().read().unwrap().clone()
+    }
+}
+
+/// Estimates the monthly cost of a configuration and services, using the current [PriceTable].
+pub fn estimate_cost(config: &Conf, templates: &[Template]) -> CostEstimate {
+    CostEstimator::current().estimate(config, templates)
+}
+
+#[cfg(test)]
+mod cost_tests {
+    use super::*;
+    use crate::resource_management::models::enums::*;
+    use crate::resource_management::models::template::*;
+
+    const SOURCE: &str = r#"
+[database]
+name = "orders"
+plan = "pro"
+version = "16"
+enableHighAvailability = true
+cidrBlocks = []
+
+[redis]
+name = "sessions"
+plan = "enterprise"
+cidrBlocks = []
+"#;
+
+    fn services() -> Vec<Template> {
+        vec![
+            Template {
+                type_: ServiceType::WebService,
+                name: "api".to_owned(),
+                service_details: Some(ServiceDetails {
+                    plan: Some(ServicePlan::Standard),
+                    num_instances: Some(2),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            Template {
+                type_: ServiceType::BackgroundWorker,
+                name: "jobs".to_owned(),
+                autoscaling: Some(AutoScaling {
+                    min: 1,
+                    max: 3,
+                    criteria: None,
+                }),
+                ..Default::default()
+            },
+            Template {
+                type_: ServiceType::StaticSite,
+                name: "docs".to_owned(),
+                ..Default::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn test_estimate_cost() {
+        let config: Conf = toml::from_str(SOURCE).unwrap();
+        let estimate = PriceTable::default().estimate(&config, &services());
+
+        let monthly: Vec<(&str, u32, f64)> = estimate
+            .items
+            .iter()
+            .map(|item| (item.name.as_str(), item.instances, item.monthly))
+            .collect();
+
+        // HA doubles postgres, autoscaled services are priced at `max`.
+        assert_eq!(
+            monthly,
+            vec![
+                ("orders", 2, 190.0),
+                ("sessions", 1, 0.0),
+                ("api", 2, 50.0),
+                ("jobs", 3, 21.0),
+                ("docs", 1, 0.0),
+            ]
+        );
+        assert_eq!(estimate.items[1].unit_price, None);
+        assert_eq!(estimate.total(), 261.0);
+        assert!(estimate.to_string().contains("261.00"));
+    }
+
+    #[test]
+    fn test_override_prices() {
+        let table = PriceTable::default()
+            .merge("currency = \"EUR\"\n[redis]\nenterprise = 500.0\n")
+            .unwrap();
+        let config: Conf = toml::from_str(SOURCE).unwrap();
+        let estimate = table.estimate(&config, &[]);
+
+        assert_eq!(estimate.currency, "EUR");
+        assert_eq!(estimate.items[1].monthly, 500.0);
+        // Plans missing from the override keep their bundled price.
+        assert_eq!(estimate.items[0].monthly, 190.0);
+    }
+}
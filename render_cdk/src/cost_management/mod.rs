@@ -0,0 +1,2 @@
+pub mod cost_estimator;
+pub mod prelude;
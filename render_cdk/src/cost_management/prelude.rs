@@ -0,0 +1,3 @@
+pub use crate::cost_management::cost_estimator::{
+    estimate_cost, CostEstimate, CostEstimator, CostItem, PriceTable,
+};
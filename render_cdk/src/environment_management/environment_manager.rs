@@ -1,7 +1,26 @@
 #![allow(missing_docs)]
 #![allow(unused)]
 // Evironment varible passing.
-use dotenvy::dotenv;
+use dotenvy::{dotenv, dotenv_iter};
+
+// Idiomatic [ERROR] handling.
+use anyhow::{Error, Result};
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Once, OnceLock, RwLock};
+
+static LOAD_DOTENV: Once = Once::new();
+
+/// Exports the nearest `.env` file into the process environment, only once per process.
+///
+/// Variables that are already set are left untouched.
+pub(crate) fn load_dot_env() {
+    LOAD_DOTENV.call_once(|| {
+        dotenv().ok();
+    });
+}
 
 #[derive(Debug, Clone)]
 #[allow(non_snake_case)]
@@ -10,17 +29,330 @@ pub struct EnvironmentManager {
     pub OWNER_CREDENTIALS: String,
 }
 
+/// The credentials `render_cdk` needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Credential {
+    ApiKey,
+    OwnerCredentials,
+}
+
+impl Credential {
+    /// The (unprefixed) variable name the credential is read from.
+    pub fn variable(&self) -> &'static str {
+        match self {
+            Credential::ApiKey => "API_KEY",
+            Credential::OwnerCredentials => "OWNER_CREDENTIALS",
+        }
+    }
+}
+
+/// A single place credentials can be read from.
+#[derive(Debug, Clone)]
+pub enum CredentialSource {
+    /// Process environment variables, i.e `{prefix}API_KEY`.
+    /// `{prefix}API_KEY_FILE` is honoured as a path to a file holding the value.
+    Environment { prefix: String },
+    /// The nearest `.env` file in the current directory or its parents.
+    DotEnv,
+    /// An explicit dotenv style file, read with the given variable prefix.
+    EnvFile { path: PathBuf, prefix: String },
+    /// A file containing only the value, e.g a Docker/K8s secrets mount.
+    SecretFile {
+        credential: Credential,
+        path: PathBuf,
+    },
+    /// A value provided directly in code.
+    Value {
+        credential: Credential,
+        value: String,
+    },
+}
+
+impl CredentialSource {
+    /// Human readable description used in resolution errors.
+    fn describe(&self, credential: Credential) -> String {
+        match self {
+            CredentialSource::Environment { prefix } => format!(
+                "environment variable `{0}{1}` (or `{0}{1}_FILE`)",
+                prefix,
+                credential.variable()
+            ),
+            CredentialSource::DotEnv => format!("`.env` file ({})", credential.variable()),
+            CredentialSource::EnvFile { path, prefix } => {
+                format!("env file {:?} ({}{})", path, prefix, credential.variable())
+            }
+            CredentialSource::SecretFile { path, .. } => format!("secret file {:?}", path),
+            CredentialSource::Value { .. } => "value provided in code".to_owned(),
+        }
+    }
+
+    fn applies_to(&self, credential: Credential) -> bool {
+        match self {
+            CredentialSource::SecretFile {
+                credential: provided,
+                ..
+            }
+            | CredentialSource::Value {
+                credential: provided,
+                ..
+            } => *provided == credential,
+            _ => true,
+        }
+    }
+
+    /// Missing files are treated as not providing a value.
+    fn lookup(&self, credential: Credential) -> Result<Option<String>, Error> {
+        match self {
+            CredentialSource::Environment { prefix } => {
+                let variable = format!("{}{}", prefix, credential.variable());
+
+                if let Ok(value) = std::env::var(&variable) {
+                    return Ok(Some(value));
+                }
+
+                match std::env::var(format!("{}_FILE", variable)) {
+                    Ok(path) => read_secret_file(&PathBuf::from(path)),
+                    Err(_) => Ok(None),
+                }
+            }
+            CredentialSource::DotEnv => {
+                load_dot_env();
+                match dotenv_iter() {
+                    Ok(entries) => find_entry(entries, credential.variable()),
+                    Err(_) => Ok(None),
+                }
+            }
+            CredentialSource::EnvFile { path, .. } if !path.exists() => Ok(None),
+            CredentialSource::EnvFile { path, prefix } => {
+                let entries = dotenvy::from_path_iter(path).map_err(|err| {
+                    anyhow::anyhow!("Unable to READ env file {:?}: {}", path, err)
+                })?;
+                find_entry(entries, &format!("{}{}", prefix, credential.variable()))
+            }
+            CredentialSource::SecretFile {
+                credential: provided,
+                path,
+            } if *provided == credential => read_secret_file(path),
+            CredentialSource::Value {
+                credential: provided,
+                value,
+            } if *provided == credential => Ok(Some(value.clone())),
+            _ => Ok(None),
+        }
+    }
+}
+
+fn find_entry(
+    entries: impl Iterator<Item = dotenvy::Result<(String, String)>>,
+    variable: &str,
+) -> Result<Option<String>, Error> {
+    for entry in entries {
+        let (key, value) =
+            entry.map_err(|err| anyhow::anyhow!("Unable to PARSE env file: {}", err))?;
+        if key == variable {
+            return Ok(Some(value));
+        }
+    }
+
+    Ok(None)
+}
+
+fn read_secret_file(path: &PathBuf) -> Result<Option<String>, Error> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    fs::read_to_string(path)
+        .map(|contents| Some(contents.trim().to_owned()))
+        .map_err(|err| anyhow::anyhow!("Unable to READ secret file {:?}: {}", path, err))
+}
+
+/// An ordered list of [CredentialSource]s, the first one providing a value wins.
+#[derive(Debug, Clone)]
+pub struct CredentialSources {
+    sources: Vec<CredentialSource>,
+}
+
+impl Default for CredentialSources {
+    /// The process environment, followed by the nearest `.env` file.
+    fn default() -> Self {
+        Self::new().environment("").dot_env()
+    }
+}
+
+impl CredentialSources {
+    /// Starts with no sources at all.
+    pub fn new() -> Self {
+        Self { sources: vec![] }
+    }
+
+    pub fn environment(mut self, prefix: &str) -> Self {
+        self.sources.push(CredentialSource::Environment {
+            prefix: prefix.to_owned(),
+        });
+        self
+    }
+
+    pub fn dot_env(mut self) -> Self {
+        self.sources.push(CredentialSource::DotEnv);
+        self
+    }
+
+    pub fn env_file(mut self, path: impl Into<PathBuf>, prefix: &str) -> Self {
+        self.sources.push(CredentialSource::EnvFile {
+            path: path.into(),
+            prefix: prefix.to_owned(),
+        });
+        self
+    }
+
+    pub fn secret_file(mut self, credential: Credential, path: impl Into<PathBuf>) -> Self {
+        self.sources.push(CredentialSource::SecretFile {
+            credential,
+            path: path.into(),
+        });
+        self
+    }
+
+    pub fn value(mut self, credential: Credential, value: &str) -> Self {
+        self.sources.push(CredentialSource::Value {
+            credential,
+            value: value.to_owned(),
+        });
+        self
+    }
+
+    /// Resolves a credential, listing every source tried when none provides it.
+    pub fn resolve(&self, credential: Credential) -> Result<String, Error> {
+        let mut tried = vec![];
+
+        for source in self.sources.iter().filter(|s| s.applies_to(credential)) {
+            match source.lookup(credential)? {
+                Some(value) if !value.is_empty() => return Ok(value),
+                _ => tried.push(source.describe(credential)),
+            }
+        }
+
+        Err(anyhow::anyhow!(
+            "[{}] must be set. Tried: {}.",
+            credential.variable(),
+            if tried.is_empty() {
+                "no sources configured".to_owned()
+            } else {
+                tried.join(", ")
+            }
+        ))
+    }
+}
+
+/// Configured sources, along with the credentials already resolved from them.
+#[derive(Debug, Default)]
+struct Resolver {
+    sources: CredentialSources,
+    resolved: HashMap<Credential, String>,
+}
+
+static RESOLVER: OnceLock<RwLock<Resolver>> = OnceLock::new();
+
+fn resolver() -> &'static RwLock<Resolver> {
+    RESOLVER.get_or_init(|| RwLock::new(Resolver::default()))
+}
+
 impl EnvironmentManager {
-    pub fn retrieve_env_config() -> Self {
-        dotenv().ok();
+    /// Replaces the sources credentials are read from, for the whole process.
+    pub fn configure(sources: CredentialSources) {
+        let mut resolver = resolver().write().unwrap();
+        resolver.sources = sources;
+        resolver.resolved.clear();
+    }
 
-        let api_key = std::env::var_os("API_KEY").expect("[API_KEY] must be set.");
-        let owner_credentials =
-            std::env::var_os("OWNER_CREDENTIALS").expect("[OWNER_CREDENTIALS] must be set.");
+    /// Resolves a single credential, only reading its sources once.
+    pub fn resolve(credential: Credential) -> Result<String, Error> {
+        if let Some(value) = resolver().read().unwrap().resolved.get(&credential) {
+            return Ok(value.clone());
+        }
 
+        let mut resolver = resolver().write().unwrap();
+        let value = resolver.sources.resolve(credential)?;
+        resolver.resolved.insert(credential, value.clone());
+
+        Ok(value)
+    }
+
+    pub fn api_key() -> Result<String, Error> {
+        EnvironmentManager::resolve(Credential::ApiKey)
+    }
+
+    pub fn owner_credentials() -> Result<String, Error> {
+        EnvironmentManager::resolve(Credential::OwnerCredentials)
+    }
+
+    /// Resolves both credentials, panicking when either is missing.
+    pub fn retrieve_env_config() -> Self {
         Self {
-            API_KEY: api_key.into_string().unwrap(),
-            OWNER_CREDENTIALS: owner_credentials.into_string().unwrap(),
+            API_KEY: EnvironmentManager::api_key().unwrap_or_else(|err| panic!("{}", err)),
+            OWNER_CREDENTIALS: EnvironmentManager::owner_credentials()
+                .unwrap_or_else(|err| panic!("{}", err)),
         }
     }
 }
+
+#[cfg(test)]
+mod environment_tests {
+    use super::*;
+
+    /// Removed once dropped.
+    fn temp_file(name: &str, contents: &str) -> tempfile::NamedTempFile {
+        let file = tempfile::Builder::new().prefix(name).tempfile().unwrap();
+        fs::write(file.path(), contents).unwrap();
+        file
+    }
+
+    #[test]
+    fn test_resolve_from_secret_file_and_value() {
+        let secret = temp_file("api_key_secret", "rnd_from_file\n");
+        let sources = CredentialSources::new()
+            .secret_file(Credential::ApiKey, secret.path())
+            .value(Credential::OwnerCredentials, "dev@example.com");
+
+        assert_eq!(
+            sources.resolve(Credential::ApiKey).unwrap(),
+            "rnd_from_file"
+        );
+        assert_eq!(
+            sources.resolve(Credential::OwnerCredentials).unwrap(),
+            "dev@example.com"
+        );
+    }
+
+    #[test]
+    fn test_resolve_from_prefixed_env_file() {
+        let env_file = temp_file(
+            "prefixed.env",
+            "RENDER_API_KEY=rnd_from_env_file\nAPI_KEY=rnd_unprefixed\n",
+        );
+        let sources = CredentialSources::new().env_file(env_file.path(), "RENDER_");
+
+        assert_eq!(
+            sources.resolve(Credential::ApiKey).unwrap(),
+            "rnd_from_env_file"
+        );
+    }
+
+    #[test]
+    fn test_resolve_lists_tried_sources() {
+        let sources = CredentialSources::new()
+            .environment("RENDER_CDK_UNSET_")
+            .secret_file(Credential::ApiKey, "/nonexistent/api_key")
+            .value(Credential::OwnerCredentials, "dev@example.com");
+
+        // The secret file does not exist, and the value only provides [OWNER_CREDENTIALS].
+        let result = sources.resolve(Credential::ApiKey);
+        let message = result.unwrap_err().to_string();
+
+        assert!(message.contains("[API_KEY] must be set."));
+        assert!(message.contains("RENDER_CDK_UNSET_API_KEY"));
+        assert!(message.contains("/nonexistent/api_key"));
+        assert!(!message.contains("value provided in code"));
+    }
+}
@@ -1 +1,3 @@
-pub use crate::environment_management::environment_manager::EnvironmentManager;
+pub use crate::environment_management::environment_manager::{
+    Credential, CredentialSource, CredentialSources, EnvironmentManager,
+};
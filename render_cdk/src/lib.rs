@@ -59,7 +59,7 @@
 //!     ServiceManager::list_services_with_status("suspended", "50").await.unwrap();
 //!
 //!     // Find a specific service by name and type
-//!     ServiceManager::find_service_by_name_and_type("whoami", "web_service").await.unwrap();
+//!     ServiceManager::find_service_by_name_and_type("whoami", ServiceType::WebService).await.unwrap();
 //!
 //!     // Find services by region
 //!     ServiceManager::find_service_by_region("oregon", "10").await.unwrap();
@@ -79,10 +79,10 @@
 //! #[main]
 //! async fn main() {
 //!     // Delete a web service
-//!     ServiceManager::delete_service("test_web", "web_service").await.unwrap();
+//!     ServiceManager::delete_service("test_web", ServiceType::WebService).await.unwrap();
 //!
 //!     // Delete a static site
-//!     ServiceManager::delete_service("test_static", "static").await.unwrap();
+//!     ServiceManager::delete_service("test_static", ServiceType::StaticSite).await.unwrap();
 //! }
 //! ```
 //!
@@ -110,17 +110,17 @@
 //! This example demonstrates how to deploy a simple static site using Render.
 //!
 //! ```ignore
-//! use render_cdk::resource_management::templates::{Template, ServiceDetails};
+//! use render_cdk::resource_management::models::prelude::*;
 //!
 //! let static_site = Template {
-//!     type_: "static_site".to_owned(),
+//!     type_: ServiceType::StaticSite,
 //!     name: "test_static".to_owned(),
 //!     repo: "https://github.com/lexara-prime-ai/SAMPLE_STATIC_SITE".to_owned(),
-//!     auto_deploy: Some("yes".to_owned()),
+//!     auto_deploy: Some(Toggle::Yes),
 //!     root_dir: Some("./public".to_owned()),
 //!     service_details: Some(ServiceDetails {
 //!         publish_path: Some("./".to_owned()),
-//!         pull_request_previews_enabled: Some("yes".to_owned()),
+//!         pull_request_previews_enabled: Some(Toggle::Yes),
 //!         ..Default::default()
 //!     }),
 //!     ..Default::default()
@@ -134,24 +134,25 @@
 //! This example demonstrates deploying a web service, specifically a Node.js application.
 //!
 //! ```ignore
-//! use render_cdk::resource_management::templates::{Template, ServiceDetails, EnvSpecificDetails};
+//! use render_cdk::resource_management::models::prelude::*;
 //!
 //! let web_service = Template {
-//!     type_: "web_service".to_owned(),
+//!     type_: ServiceType::WebService,
 //!     name: "test_web".to_owned(),
 //!     repo: "https://github.com/lexara-prime-ai/SAMPLE_WEB_SERVICE".to_owned(),
-//!     auto_deploy: Some("yes".to_owned()),
+//!     auto_deploy: Some(Toggle::Yes),
 //!     root_dir: Some("./".to_owned()),
 //!     service_details: Some(ServiceDetails {
-//!         region: Some("oregon".to_owned()),
-//!         plan: Some("starter".to_owned()),
-//!         runtime: Some("node".to_owned()),
+//!         region: Some(Region::Oregon),
+//!         plan: Some(ServicePlan::Starter),
+//!         runtime: Some(Runtime::Node),
 //!         num_instances: Some(1),
 //!         env_specific_details: Some(EnvSpecificDetails {
 //!             build_command: Some("yarn".to_owned()),
 //!             start_command: Some("npm start".to_owned()),
+//!             ..Default::default()
 //!         }),
-//!         pull_request_previews_enabled: Some("yes".to_owned()),
+//!         pull_request_previews_enabled: Some(Toggle::Yes),
 //!         ..Default::default()
 //!     }),
 //!     ..Default::default()
@@ -199,11 +200,11 @@
 //! plan = "starter"
 //! version = "12"
 //! name = ""
-//! # The following portion enables <public> access.
+//! # The following portion restricts access to a private network.
+//! # An empty list blocks all external access.
 //! cidrBlocks = [
-//!     { cidrBlock = "0.0.0.0/0", description = "Everywhere" }
-//!     # { cidrBlock = "0.0.0.0/0", description = "Everywhere" },
-//!     # { cidrBlock = "0.0.0.0/0", description = "Everywhere" }
+//!     { cidrBlock = "10.0.0.0/16", description = "Private network" }
+//!     # { cidrBlock = "192.168.1.0/24", description = "Office network" },
 //!     # Add more CIDR blocks here...
 //! ]
 //!
@@ -211,9 +212,8 @@
 //! # name = ""
 //! # plan = "starter"
 //! # cidrBlocks = [
-//! #     { cidrBlock = "0.0.0.0/0", description = "Everywhere" }
-//! #     # { cidrBlock = "0.0.0.0/0", description = "Everywhere" },
-//! #     # { cidrBlock = "0.0.0.0/0", description = "Everywhere" }
+//! #     { cidrBlock = "10.0.0.0/16", description = "Private network" }
+//! #     # { cidrBlock = "192.168.1.0/24", description = "Office network" },
 //! #     # Add more CIDR blocks here...
 //! # ]
 //! ```
@@ -247,10 +247,10 @@
 //! #[tokio::main]
 //! async fn main() {
 //!     // Delete a static site deployment.
-//!     ServiceManager::delete_service("test_deployment", "static").await;
+//!     ServiceManager::delete_service("test_deployment", ServiceType::StaticSite).await;
 //!
 //!    // Delete a web service deployment.
-//!     ServiceManager::delete_service("test_deployment", "web_service").await;
+//!     ServiceManager::delete_service("test_deployment", ServiceType::WebService).await;
 //!
 //!     // Delete a postgres instance.
 //!     ServiceManager::delete_postgres_instance("test_postgres").await;
@@ -261,8 +261,13 @@
 //!
 
 pub mod authentication;
+pub mod cost_management;
 pub mod environment_management;
 pub mod logger;
+pub mod policy_management;
 pub mod resource_management;
 pub mod state_management;
+#[cfg(any(test, feature = "testing"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
 pub mod utils;
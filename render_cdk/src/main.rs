@@ -1,6 +1,6 @@
 #![allow(unused)]
-use render_cdk::authentication::owner::Info;
-use render_cdk::resource_management::models::template::*;
+use render_cdk::authentication::owner::{Info, OwnerSelector};
+use render_cdk::resource_management::models::prelude::*;
 use render_cdk::resource_management::prelude::*;
 use render_cdk::utils::config::Conf;
 
@@ -9,6 +9,10 @@ use colored::Colorize;
 use render_cdk::logger::prelude::*;
 use render_cdk::LOGGER;
 
+// Idiomatic [ERROR] handling.
+use anyhow::Error;
+
+use futures::StreamExt;
 use std::default::Default;
 use tokio::main;
 use tokio::time::{sleep, Duration};
@@ -16,6 +20,24 @@ use tokio::time::{sleep, Duration};
 /// Usage Examples.
 #[main]
 async fn main() {
+    // render_cdk logs <name> [--type <type>] [--follow] ...
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("logs") {
+        match LogsCommand::parse(&args[1..]) {
+            Ok(command) => {
+                if let Err(error) = command.run().await {
+                    eprintln!("{}", error);
+                    std::process::exit(1);
+                }
+            }
+            Err(error) => {
+                eprintln!("{}\n\n{}", error, LOGS_USAGE);
+                std::process::exit(2);
+            }
+        }
+        return;
+    }
+
     /*
       _____                 _
      / ____|               (_)
@@ -28,7 +50,7 @@ async fn main() {
 
     // ServiceManager::list_all_services("50").await;
     // ServiceManager::list_services_with_status("suspended", "50").await;
-    // ServiceManager::find_service_by_name_and_type("test_deployment", "static").await;
+    // ServiceManager::find_service_by_name_and_type("test_deployment", ServiceType::StaticSite).await;
     // ServiceManager::find_service_by_region("oregon", "50").await;
     // ServiceManager::find_service_by_environment("image", "50").await;
 
@@ -72,10 +94,23 @@ async fn main() {
 
     // let instances = ServiceManager::find_redis_instance_by_name("cyberplasma", "50").await;
 
-    // Retrieve Owner Id.
+    // Retrieve Owner Id (resolved once, then cached).
     // let owner = Info::get_owner_id().await;
     // println!("{}", owner);
 
+    // Show the owner deployments are made under, or force another lookup.
+    // let owner = Info::whoami().await.unwrap();
+    // let owner = Info::refresh_owner().await.unwrap();
+
+    // List every owner/workspace the [API_KEY] can access, then select one explicitly.
+    // let workspaces = Info::list_workspaces().await.unwrap();
+    // let team = Info::find_owner(&OwnerSelector::Name("my-team".to_owned()))
+    //     .await
+    //     .unwrap();
+
+    // Alternatively, make the selected owner the default for every deployment.
+    // Info::use_owner(&OwnerSelector::Name("my-team".to_owned())).await.unwrap();
+
     // let config = Conf::read_configuration_file("./samples/sample.conf").unwrap();
 
     /*
@@ -86,14 +121,14 @@ async fn main() {
     */
 
     let static_site = Template {
-        type_: "static_site".to_owned(),
+        type_: ServiceType::StaticSite,
         name: "test_static".to_owned(),
         repo: "https://github.com/lexara-prime-ai/SAMPLE_STATIC_SITE".to_owned(),
-        auto_deploy: Some("yes".to_owned()),
+        auto_deploy: Some(Toggle::Yes),
         root_dir: Some("./public".to_owned()),
         service_details: Some(ServiceDetails {
             publish_path: Some("./".to_owned()),
-            pull_request_previews_enabled: Some("yes".to_owned()),
+            pull_request_previews_enabled: Some(Toggle::Yes),
             ..Default::default()
         }),
         ..Default::default()
@@ -121,21 +156,22 @@ async fn main() {
     */
 
     let web_service = Template {
-        type_: "web_service".to_owned(),
+        type_: ServiceType::WebService,
         name: "test_web".to_owned(),
         repo: "https://github.com/lexara-prime-ai/SAMPLE_WEB_SERVICE".to_owned(),
-        auto_deploy: Some("yes".to_owned()),
+        auto_deploy: Some(Toggle::Yes),
         root_dir: Some("./".to_owned()),
         service_details: Some(ServiceDetails {
-            region: Some("oregon".to_owned()),
-            plan: Some("starter".to_owned()),
-            runtime: Some("node".to_owned()),
+            region: Some(Region::Oregon),
+            plan: Some(ServicePlan::Starter),
+            runtime: Some(Runtime::Node),
             num_instances: Some(1),
             env_specific_details: Some(EnvSpecificDetails {
                 build_command: Some("yarn".to_owned()),
                 start_command: Some("npm start".to_owned()),
+                ..Default::default()
             }),
-            pull_request_previews_enabled: Some("yes".to_owned()),
+            pull_request_previews_enabled: Some(Toggle::Yes),
             ..Default::default()
         }),
         ..Default::default()
@@ -147,6 +183,10 @@ async fn main() {
     // DEPLOY A <web_service>.
     // ServiceManager::create_service(web_service).await;
 
+    // DEPLOY INTO AN EXPLICITLY SELECTED <workspace>.
+    // ServiceManager::create_service_for_owner(static_site, &team.id).await;
+    // ServiceManager::deploy_configuration_for_owner("./samples/sample.conf", &team.id).await;
+
     // Deploy existing configuration.
     // ServiceManager::deploy_configuration("./samples/sample.conf")
     //     .await
@@ -166,16 +206,118 @@ async fn main() {
 
     */
     // sleep(Duration::from_secs(150)).await;
-    // ServiceManager::delete_service("test_static", "static").await;
-    // ServiceManager::delete_service("test_web", "web_service").await;
+    // ServiceManager::delete_service("test_static", ServiceType::StaticSite).await;
+    // ServiceManager::delete_service("test_web", ServiceType::WebService).await;
     // ServiceManager::delete_postgres_instance("gearednimbus").await;
     // ServiceManager::delete_redis_instance("cyberplasma").await;
 }
 
+const LOGS_USAGE: &str = "Usage: render_cdk logs <name> [--type web_service|static_site|private_service|background_worker|cron_job|postgres|redis] [--follow] [--level <level>] [--text <text>] [--instance <id>] [--log-type app|request|build] [--deploy <id>] [--since <rfc3339>] [--until <rfc3339>] [--limit <n>]";
+
+/// `logs` command, listing or following the logs of a resource.
+#[derive(Debug)]
+struct LogsCommand {
+    name: String,
+    kind: ResourceKind,
+    filters: LogFilters,
+    follow: bool,
+    limit: String,
+}
+
+impl LogsCommand {
+    /// Reads the arguments following `logs`, repeated filters match any of the values.
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut command = LogsCommand {
+            name: String::new(),
+            kind: ResourceKind::Service(ServiceType::WebService),
+            filters: LogFilters::default(),
+            follow: false,
+            limit: "20".to_owned(),
+        };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if arg == "--follow" || arg == "-f" {
+                command.follow = true;
+                continue;
+            }
+            if !arg.starts_with('-') {
+                if !command.name.is_empty() {
+                    return Err(format!("Unexpected argument {:?}.", arg));
+                }
+                command.name = arg.clone();
+                continue;
+            }
+
+            let value = args
+                .next()
+                .ok_or_else(|| format!("Missing value of {}.", arg))?
+                .clone();
+            let time = |value: &str| {
+                value
+                    .parse()
+                    .map_err(|_| format!("Invalid time {:?} of {}, expected RFC 3339.", value, arg))
+            };
+            match arg.as_str() {
+                "--type" => {
+                    command.kind = match value.as_str() {
+                        "postgres" => ResourceKind::Postgres,
+                        "redis" => ResourceKind::Redis,
+                        service_type => {
+                            let service_type = ServiceType::from(service_type);
+                            if !service_type.is_known() {
+                                return Err(format!("Unknown resource type {:?}.", value));
+                            }
+                            ResourceKind::Service(service_type)
+                        }
+                    }
+                }
+                "--level" => command.filters.levels.push(value),
+                "--text" => command.filters.text.push(value),
+                "--instance" => command.filters.instances.push(value),
+                "--log-type" => command.filters.types.push(value),
+                "--deploy" => command.filters.deploy_id = Some(value),
+                "--since" => command.filters.start_time = Some(time(&value)?),
+                "--until" => command.filters.end_time = Some(time(&value)?),
+                "--limit" => command.limit = value,
+                _ => return Err(format!("Unknown option {:?}.", arg)),
+            }
+        }
+
+        if command.name.is_empty() {
+            return Err("Missing the name of the resource.".to_owned());
+        }
+        Ok(command)
+    }
+
+    async fn run(self) -> Result<(), Error> {
+        if !self.follow {
+            let page =
+                LogManager::list_logs(&self.name, self.kind, self.filters, &self.limit).await?;
+            // Printed oldest first, like a terminal.
+            page.logs.iter().rev().for_each(|log| println!("{}", log));
+            return Ok(());
+        }
+
+        let mut logs = LogManager::tail_logs(&self.name, self.kind, self.filters).await?;
+        while let Some(log) = logs.next().await {
+            match log {
+                Ok(log) => println!("{}", log),
+                // Polling goes on after a failed request.
+                Err(error) => eprintln!("{}", error),
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Mandatory Regression Tests.
 ///
 /// These checks are there to validate that it is functioning properly
 /// and returning the right results, after which we shall describe each test case.
+/// They replay the interactions stored in `./samples/fixtures/regression_tests.json`, which were
+/// generated against the mock server of the `testing` feature. Running them with `RENDER_CDK_RECORD=1`
+/// and real credentials replaces the fixture with a recording of the live Render API.
 /// List all Services.
 ///
 /// This test confirms if the function list_all_services returns all services available.
@@ -192,17 +334,21 @@ async fn main() {
 /// }
 ///
 /// More tests...
-
 #[cfg(test)]
 mod regression_tests {
     use super::*;
+    use render_cdk::testing::prelude::*;
     use tokio::time::{sleep, Duration};
 
+    const REGRESSION_FIXTURE: &str = "./samples/fixtures/regression_tests.json";
+
     ///////////////////////
     // Service Management.
     ////////////////////////
     #[tokio::test]
     async fn test_list_all_services() {
+        recorded(REGRESSION_FIXTURE);
+
         let result = ServiceManager::list_all_services("10").await;
         // The result should be Ok().
         assert!(result.is_ok());
@@ -214,6 +360,8 @@ mod regression_tests {
 
     #[tokio::test]
     async fn test_list_all_postgres_instances() {
+        recorded(REGRESSION_FIXTURE);
+
         let result = ServiceManager::list_postgres_instances(true, "10").await;
         // The result should be Ok().
         assert!(result.is_ok());
@@ -225,6 +373,8 @@ mod regression_tests {
 
     #[tokio::test]
     async fn test_list_services_with_status() {
+        recorded(REGRESSION_FIXTURE);
+
         let results = ServiceManager::list_services_with_status("suspended", "10").await;
         // The result should be Ok().
         assert!(results.is_ok());
@@ -236,6 +386,8 @@ mod regression_tests {
 
     #[tokio::test]
     async fn test_find_postgres_instance_with_status() {
+        recorded(REGRESSION_FIXTURE);
+
         let results =
             ServiceManager::find_postgres_instance_with_status("suspended", true, "50").await;
         // The result should be Ok().
@@ -248,7 +400,10 @@ mod regression_tests {
 
     #[tokio::test]
     async fn test_find_service_by_name_and_type() {
-        let result = ServiceManager::find_service_by_name_and_type("whoami", "web_service").await;
+        recorded(REGRESSION_FIXTURE);
+
+        let result =
+            ServiceManager::find_service_by_name_and_type("whoami", ServiceType::WebService).await;
         // The result should be Ok().
         assert!(result.is_ok());
 
@@ -259,6 +414,8 @@ mod regression_tests {
 
     #[tokio::test]
     async fn test_find_postgres_instance_by_name() {
+        recorded(REGRESSION_FIXTURE);
+
         let result =
             ServiceManager::find_postgres_instance_by_name("fluentcomet", true, "100").await;
         // The result should be Ok().
@@ -271,6 +428,8 @@ mod regression_tests {
 
     #[tokio::test]
     async fn test_find_redis_instance_by_name() {
+        recorded(REGRESSION_FIXTURE);
+
         let result = ServiceManager::find_redis_instance_by_name("fluentcomet", "100").await;
         // The result should be Ok().
         assert!(result.is_ok());
@@ -282,6 +441,8 @@ mod regression_tests {
 
     #[tokio::test]
     async fn test_find_service_by_region() {
+        recorded(REGRESSION_FIXTURE);
+
         let result = ServiceManager::find_service_by_region("oregon", "10").await;
         // The result should be Ok().
         assert!(result.is_ok());
@@ -293,6 +454,8 @@ mod regression_tests {
 
     #[tokio::test]
     async fn test_find_service_by_environment() {
+        recorded(REGRESSION_FIXTURE);
+
         let result = ServiceManager::find_service_by_environment("image", "10").await;
 
         // The reult should be Ok().
@@ -306,15 +469,15 @@ mod regression_tests {
     #[tokio::test]
     async fn test_create_static() {
         let deployment_config = Template {
-            type_: "static_site".to_owned(),
+            type_: ServiceType::StaticSite,
             name: "test_deployment".to_owned(),
             repo: "https://github.com/lexara-prime-ai/SAMPLE_STATIC_SITE".to_owned(),
-            auto_deploy: Some("yes".to_owned()),
+            auto_deploy: Some(Toggle::Yes),
             root_dir: Some("./public".to_owned()),
             service_details: Some(ServiceDetails {
                 build_command: None,
                 publish_path: Some("./".to_owned()),
-                pull_request_previews_enabled: Some("yes".to_owned()),
+                pull_request_previews_enabled: Some(Toggle::Yes),
                 ..Default::default()
             }),
             ..Default::default()
@@ -342,7 +505,7 @@ mod regression_tests {
             .unwrap()
             .pull_request_previews_enabled;
 
-        assert!(!service_type.is_empty(), "Service type should be set.");
+        assert!(service_type.is_known(), "Service type should be set.");
         assert!(!service_name.is_empty(), "Service name should be set.");
         assert!(!repo_url.is_empty(), "Repo url should be set.");
         assert!(auto_deploy.is_some(), "Auto deploy should be set.");
@@ -350,6 +513,38 @@ mod regression_tests {
         assert_eq!(root_dir, Some("./public".to_owned()));
         assert_eq!(build_command, None);
         assert_eq!(publish_path, Some("./".to_owned()));
-        assert_eq!(pull_request_reviews_enabled, Some("yes".to_owned()));
+        assert_eq!(pull_request_reviews_enabled, Some(Toggle::Yes));
+    }
+}
+
+#[cfg(test)]
+mod cli_tests {
+    use super::*;
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(str::to_owned).collect()
+    }
+
+    #[test]
+    fn test_parse_logs_command() {
+        let command = LogsCommand::parse(&args(
+            "fluentcomet --type postgres -f --level error --level warning --since 2024-01-01T00:00:00Z",
+        ))
+        .unwrap();
+        assert_eq!(command.name, "fluentcomet");
+        assert_eq!(command.kind, ResourceKind::Postgres);
+        assert!(command.follow);
+        assert_eq!(command.filters.levels, ["error", "warning"]);
+        assert!(command.filters.start_time.is_some());
+
+        let command = LogsCommand::parse(&args("whoami --deploy dep-a1b2 --limit 50")).unwrap();
+        assert_eq!(command.kind, ResourceKind::Service(ServiceType::WebService));
+        assert_eq!(command.filters.deploy_id.as_deref(), Some("dep-a1b2"));
+        assert_eq!(command.limit, "50");
+
+        assert!(LogsCommand::parse(&args("--follow")).is_err());
+        assert!(LogsCommand::parse(&args("whoami --since yesterday")).is_err());
+        assert!(LogsCommand::parse(&args("whoami --type lambda")).is_err());
+        assert!(LogsCommand::parse(&args("whoami --level")).is_err());
     }
 }
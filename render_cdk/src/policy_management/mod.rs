@@ -0,0 +1,3 @@
+pub mod policy_manager;
+pub mod prelude;
+pub mod rules;
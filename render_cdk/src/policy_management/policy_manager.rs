@@ -0,0 +1,437 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+// Idiomatic [ERROR] handling.
+use anyhow::Error;
+
+use std::fmt;
+use std::sync::{Arc, OnceLock, RwLock};
+
+// [render_cdk] modules.
+use crate::policy_management::rules::*;
+use crate::resource_management::models::postgres::PostgresUpdate;
+use crate::resource_management::models::redis::RedisUpdate;
+use crate::resource_management::models::template::{ServiceUpdate, Template};
+use crate::utils::config::Conf;
+
+// [DEBUG] utils.
+use crate::logger::prelude::*;
+use crate::LOGGER;
+use colored::Colorize;
+
+/// What happens when a rule is violated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Enforcement {
+    /// The rule is not evaluated.
+    Off,
+    /// Violations are logged, the deployment proceeds.
+    Warn,
+    /// Violations are logged, the deployment is rejected.
+    Block,
+}
+
+/// A resource about to be deployed, or changes about to be applied to one.
+#[derive(Debug, Clone, Copy)]
+pub enum Resource<'a> {
+    Configuration(&'a Conf),
+    Service(&'a Template),
+    PostgresUpdate(&'a PostgresUpdate),
+    RedisUpdate(&'a RedisUpdate),
+    ServiceUpdate(&'a ServiceUpdate),
+}
+
+/// Information about the deployment rules can depend on.
+#[derive(Debug, Clone, Default)]
+pub struct PolicyContext {
+    pub production: bool,
+}
+
+/// A single problem found by a rule, located by its field path i.e `database.plan`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub field: String,
+    pub message: String,
+}
+
+impl Finding {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Built-in and user defined checks, see [crate::policy_management::rules].
+pub trait Rule: Send + Sync {
+    /// A unique name, used to reconfigure the rule's [Enforcement].
+    fn name(&self) -> &str;
+
+    fn check(&self, resource: &Resource<'_>, context: &PolicyContext) -> Vec<Finding>;
+}
+
+/// A [Finding], along with the rule that reported it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub rule: String,
+    pub field: String,
+    pub message: String,
+    pub enforcement: Enforcement,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = match self.enforcement {
+            Enforcement::Block => "BLOCK",
+            _ => "WARN",
+        };
+        write!(
+            f,
+            "[{}] {}: `{}` {}",
+            level, self.rule, self.field, self.message
+        )
+    }
+}
+
+/// Every violation found while evaluating a [Policy].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PolicyReport {
+    pub violations: Vec<Violation>,
+}
+
+impl PolicyReport {
+    pub fn is_empty(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Whether any violated rule blocks the deployment.
+    pub fn is_blocked(&self) -> bool {
+        self.blocking().next().is_some()
+    }
+
+    pub fn blocking(&self) -> impl Iterator<Item = &Violation> {
+        self.violations
+            .iter()
+            .filter(|v| v.enforcement == Enforcement::Block)
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = &Violation> {
+        self.violations
+            .iter()
+            .filter(|v| v.enforcement == Enforcement::Warn)
+    }
+}
+
+impl fmt::Display for PolicyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Found {} policy violation(s):", self.violations.len())?;
+        for violation in &self.violations {
+            writeln!(f, "  - {}", violation)?;
+        }
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::error::Error for PolicyReport {}
+
+#[derive(Clone)]
+struct ConfiguredRule {
+    rule: Arc<dyn Rule>,
+    enforcement: Enforcement,
+}
+
+impl fmt::Debug for ConfiguredRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConfiguredRule")
+            .field("rule", &self.rule.name())
+            .field("enforcement", &self.enforcement)
+            .finish()
+    }
+}
+
+/// An ordered set of rules, each with its own [Enforcement].
+#[derive(Debug, Clone)]
+pub struct Policy {
+    context: PolicyContext,
+    rules: Vec<ConfiguredRule>,
+}
+
+impl Default for Policy {
+    /// The built-in rules, blocking world-open CIDR blocks and free plans in production.
+    fn default() -> Self {
+        Self::new()
+            .rule(NoWorldOpenCidr, Enforcement::Block)
+            .rule(HighAvailabilityOnProductionPlans, Enforcement::Warn)
+            .rule(NoPlaintextSecrets, Enforcement::Warn)
+            .rule(NoFreePlansInProduction, Enforcement::Block)
+    }
+}
+
+impl Policy {
+    /// Starts with no rules at all.
+    pub fn new() -> Self {
+        Self {
+            context: PolicyContext::default(),
+            rules: vec![],
+        }
+    }
+
+    /// Marks deployments as production deployments.
+    pub fn production(mut self, production: bool) -> Self {
+        self.context.production = production;
+        self
+    }
+
+    /// Adds a rule, replacing any rule with the same name.
+    pub fn rule(mut self, rule: impl Rule + 'static, enforcement: Enforcement) -> Self {
+        self.rules.retain(|r| r.rule.name() != rule.name());
+        self.rules.push(ConfiguredRule {
+            rule: Arc::new(rule),
+            enforcement,
+        });
+        self
+    }
+
+    /// Changes the [Enforcement] of a rule that was already added.
+    pub fn enforce(mut self, name: &str, enforcement: Enforcement) -> Self {
+        for configured in self.rules.iter_mut().filter(|r| r.rule.name() == name) {
+            configured.enforcement = enforcement;
+        }
+        self
+    }
+
+    pub fn evaluate(&self, resource: &Resource<'_>) -> PolicyReport {
+        let mut violations = vec![];
+
+        for configured in &self.rules {
+            if configured.enforcement == Enforcement::Off {
+                continue;
+            }
+
+            for finding in configured.rule.check(resource, &self.context) {
+                violations.push(Violation {
+                    rule: configured.rule.name().to_owned(),
+                    field: finding.field,
+                    message: finding.message,
+                    enforcement: configured.enforcement,
+                });
+            }
+        }
+
+        PolicyReport { violations }
+    }
+
+    /// Logs every violation, failing with the [PolicyReport] when a blocking rule is violated.
+    pub fn check(&self, resource: &Resource<'_>) -> Result<PolicyReport, Error> {
+        let report = self.evaluate(resource);
+
+        for violation in report.warnings() {
+            LOGGER!(
+                "[POLICY] :: Warning -> ",
+                violation.to_string(),
+                LogLevel::WARN
+            );
+        }
+
+        if report.is_blocked() {
+            LOGGER!(
+                "[POLICY] :: Deployment blocked. -> ",
+                report.to_string(),
+                LogLevel::CRITICAL
+            );
+            return Err(report.into());
+        }
+
+        Ok(report)
+    }
+}
+
+static POLICY: OnceLock<RwLock<Arc<Policy>>> = OnceLock::new();
+
+fn policy() -> &'static RwLock<Arc<Policy>> {
+    POLICY.get_or_init(|| RwLock::new(Arc::new(Policy::default())))
+}
+
+/// The process wide [Policy] every deployment is checked against.
+#[derive(Debug)]
+pub struct PolicyManager;
+
+impl PolicyManager {
+    /// Replaces the policy, for the whole process.
+    pub fn configure(policy: Policy) {
+        *self::policy().write().unwrap() = Arc::new(policy);
+    }
+
+    pub fn current() -> Arc<Policy> {
+        policy().read().unwrap().clone()
+    }
+
+    /// Checks a resource against the current policy, see [Policy::check].
+    pub fn check(resource: &Resource<'_>) -> Result<PolicyReport, Error> {
+        PolicyManager::current().check(resource)
+    }
+}
+
+#[cfg(test)]
+mod policy_tests {
+    use super::*;
+    use crate::resource_management::models::prelude::*;
+
+    const SOURCE: &str = r#"
+[database]
+plan = "pro"
+version = "16"
+enableHighAvailability = false
+cidrBlocks = [{ cidrBlock = "0.0.0.0/0", description = "Everywhere" }]
+
+[redis]
+plan = "free"
+cidrBlocks = [{ cidrBlock = "10.0.0.0/16", description = "VPC" }]
+"#;
+
+    fn rules(report: &PolicyReport) -> Vec<(&str, Enforcement)> {
+        report
+            .violations
+            .iter()
+            .map(|v| (v.rule.as_str(), v.enforcement))
+            .collect()
+    }
+
+    #[test]
+    fn test_default_policy() {
+        let config: Conf = toml::from_str(SOURCE).unwrap();
+        let resource = Resource::Configuration(&config);
+
+        let report = Policy::default().evaluate(&resource);
+        assert_eq!(
+            rules(&report),
+            vec![
+                ("no-world-open-cidr", Enforcement::Block),
+                ("high-availability-on-production-plans", Enforcement::Warn),
+            ]
+        );
+        assert_eq!(
+            report.violations[0].field,
+            "database.cidrBlocks[0].cidrBlock"
+        );
+        assert!(Policy::default().check(&resource).is_err());
+
+        // Free plans are only rejected in production.
+        let report = Policy::default().production(true).evaluate(&resource);
+        assert!(report
+            .blocking()
+            .any(|v| v.rule == "no-free-plans-in-production" && v.field == "redis.plan"));
+    }
+
+    #[test]
+    fn test_reconfigured_enforcement() {
+        let config: Conf = toml::from_str(SOURCE).unwrap();
+        let resource = Resource::Configuration(&config);
+        let policy = Policy::default()
+            .enforce("no-world-open-cidr", Enforcement::Warn)
+            .enforce("high-availability-on-production-plans", Enforcement::Off);
+
+        let report = policy.check(&resource).unwrap();
+        assert_eq!(
+            rules(&report),
+            vec![("no-world-open-cidr", Enforcement::Warn)]
+        );
+    }
+
+    #[test]
+    fn test_update_policy() {
+        let update = PostgresUpdate {
+            plan: Some("pro_8gb".to_owned()),
+            enableHighAvailability: Some(false),
+            ipAllowList: Some(vec![storage::PostgresCidrAllowList {
+                cidrBlock: "0.0.0.0/0".to_owned(),
+                description: "Everywhere".to_owned(),
+            }]),
+            ..Default::default()
+        };
+
+        let report = Policy::default().evaluate(&Resource::PostgresUpdate(&update));
+        assert_eq!(
+            rules(&report),
+            vec![
+                ("no-world-open-cidr", Enforcement::Block),
+                ("high-availability-on-production-plans", Enforcement::Warn),
+            ]
+        );
+        assert_eq!(report.violations[0].field, "ipAllowList[0].cidrBlock");
+
+        // Fields left out are not checked.
+        let update = PostgresUpdate {
+            plan: Some("pro_8gb".to_owned()),
+            ..Default::default()
+        };
+        assert!(Policy::default()
+            .evaluate(&Resource::PostgresUpdate(&update))
+            .is_empty());
+
+        let update = ServiceUpdate {
+            service_details: Some(ServiceDetails {
+                plan: Some(ServicePlan::Free),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let report = Policy::default()
+            .production(true)
+            .evaluate(&Resource::ServiceUpdate(&update));
+        assert_eq!(
+            rules(&report),
+            vec![("no-free-plans-in-production", Enforcement::Block)]
+        );
+    }
+
+    struct RequireHealthCheck;
+
+    impl Rule for RequireHealthCheck {
+        fn name(&self) -> &str {
+            "require-health-check"
+        }
+
+        fn check(&self, resource: &Resource<'_>, _: &PolicyContext) -> Vec<Finding> {
+            match resource {
+                Resource::Service(template) if template.health_check_path.is_none() => {
+                    vec![Finding::new("healthCheckPath", "is required")]
+                }
+                _ => vec![],
+            }
+        }
+    }
+
+    #[test]
+    fn test_user_rules_and_secrets() {
+        let template = Template {
+            type_: ServiceType::WebService,
+            name: "api".to_owned(),
+            env_vars: vec![
+                EnvVar {
+                    key: "DATABASE_PASSWORD".to_owned(),
+                    value: Some("hunter2".to_owned()),
+                    generate_value: false,
+                },
+                EnvVar {
+                    key: "SESSION_SECRET".to_owned(),
+                    value: None,
+                    generate_value: true,
+                },
+            ],
+            ..Default::default()
+        };
+
+        let report = Policy::default()
+            .rule(RequireHealthCheck, Enforcement::Block)
+            .evaluate(&Resource::Service(&template));
+
+        assert_eq!(
+            rules(&report),
+            vec![
+                ("no-plaintext-secrets", Enforcement::Warn),
+                ("require-health-check", Enforcement::Block),
+            ]
+        );
+        assert_eq!(report.violations[0].field, "envVars[0].value");
+    }
+}
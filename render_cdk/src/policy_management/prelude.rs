@@ -0,0 +1,5 @@
+pub use crate::policy_management::policy_manager::{
+    Enforcement, Finding, Policy, PolicyContext, PolicyManager, PolicyReport, Resource, Rule,
+    Violation,
+};
+pub use crate::policy_management::rules::*;
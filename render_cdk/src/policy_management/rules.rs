@@ -0,0 +1,211 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+// [render_cdk] modules.
+use crate::policy_management::policy_manager::{Finding, PolicyContext, Resource, Rule};
+use crate::utils::validation::parse_cidr;
+
+/// Env var keys that usually hold secrets.
+pub const SECRET_KEYWORDS: &[&str] = &[
+    "PASSWORD",
+    "SECRET",
+    "TOKEN",
+    "API_KEY",
+    "PRIVATE_KEY",
+    "CREDENTIALS",
+];
+
+/// Rejects CIDR blocks allowing access from anywhere, i.e `0.0.0.0/0` or `::/0`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoWorldOpenCidr;
+
+impl Rule for NoWorldOpenCidr {
+    fn name(&self) -> &str {
+        "no-world-open-cidr"
+    }
+
+    fn check(&self, resource: &Resource<'_>, _: &PolicyContext) -> Vec<Finding> {
+        let blocks: Vec<(String, &String)> = match resource {
+            Resource::Configuration(config) => {
+                let database = config.database.iter().flat_map(|database| {
+                    database
+                        .cidrBlocks
+                        .iter()
+                        .enumerate()
+                        .map(|(index, block)| {
+                            (format!("database.cidrBlocks[{}]", index), &block.cidrBlock)
+                        })
+                });
+                let redis = config.redis.iter().flat_map(|redis| {
+                    redis.cidrBlocks.iter().enumerate().map(|(index, block)| {
+                        (format!("redis.cidrBlocks[{}]", index), &block.cidrBlock)
+                    })
+                });
+                database.chain(redis).collect()
+            }
+            Resource::PostgresUpdate(update) => update
+                .ipAllowList
+                .iter()
+                .flatten()
+                .enumerate()
+                .map(|(index, block)| (format!("ipAllowList[{}]", index), &block.cidrBlock))
+                .collect(),
+            Resource::RedisUpdate(update) => update
+                .ipAllowList
+                .iter()
+                .flatten()
+                .enumerate()
+                .map(|(index, block)| (format!("ipAllowList[{}]", index), &block.cidrBlock))
+                .collect(),
+            Resource::Service(_) | Resource::ServiceUpdate(_) => vec![],
+        };
+
+        blocks
+            .into_iter()
+            .filter(|(_, cidr)| matches!(parse_cidr(cidr), Some((_, 0))))
+            .map(|(path, cidr)| {
+                Finding::new(
+                    format!("{}.cidrBlock", path),
+                    format!(
+                        "allows access from anywhere ({:?}), restrict it to known networks",
+                        cidr
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Requires high availability for Postgres instances on `pro` and `accelerated` plans.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HighAvailabilityOnProductionPlans;
+
+impl Rule for HighAvailabilityOnProductionPlans {
+    fn name(&self) -> &str {
+        "high-availability-on-production-plans"
+    }
+
+    fn check(&self, resource: &Resource<'_>, _: &PolicyContext) -> Vec<Finding> {
+        let (field, plan, enabled) = match resource {
+            Resource::Configuration(config) => match &config.database {
+                Some(database) => (
+                    "database.enableHighAvailability",
+                    database.plan.as_str(),
+                    database.enableHighAvailability,
+                ),
+                None => return vec![],
+            },
+            // `update_postgres` fills in the current plan and high availability.
+            Resource::PostgresUpdate(update) => match (&update.plan, update.enableHighAvailability)
+            {
+                (Some(plan), Some(enabled)) => ("enableHighAvailability", plan.as_str(), enabled),
+                _ => return vec![],
+            },
+            _ => return vec![],
+        };
+
+        if is_production_plan(plan) && !enabled {
+            vec![Finding::new(
+                field,
+                format!("must be enabled on the {:?} plan", plan),
+            )]
+        } else {
+            vec![]
+        }
+    }
+}
+
+fn is_production_plan(plan: &str) -> bool {
+    plan == "pro"
+        || plan == "pro_plus"
+        || plan.starts_with("pro_")
+        || plan.starts_with("accelerated_")
+}
+
+/// Rejects literal env var values for keys that look like secrets, see [SECRET_KEYWORDS].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoPlaintextSecrets;
+
+impl Rule for NoPlaintextSecrets {
+    fn name(&self) -> &str {
+        "no-plaintext-secrets"
+    }
+
+    fn check(&self, resource: &Resource<'_>, _: &PolicyContext) -> Vec<Finding> {
+        let Resource::Service(template) = resource else {
+            return vec![];
+        };
+
+        template
+            .env_vars
+            .iter()
+            .enumerate()
+            .filter(|(_, env_var)| {
+                let key = env_var.key.to_uppercase();
+                let provided = env_var.value.as_deref().is_some_and(|v| !v.is_empty());
+
+                provided
+                    && !env_var.generate_value
+                    && SECRET_KEYWORDS.iter().any(|keyword| key.contains(keyword))
+            })
+            .map(|(index, env_var)| {
+                Finding::new(
+                    format!("envVars[{}].value", index),
+                    format!(
+                        "holds a plaintext secret for {:?}, use `generateValue` or a secret file instead",
+                        env_var.key
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Rejects `free` plans, only in production, see [PolicyContext].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoFreePlansInProduction;
+
+impl Rule for NoFreePlansInProduction {
+    fn name(&self) -> &str {
+        "no-free-plans-in-production"
+    }
+
+    fn check(&self, resource: &Resource<'_>, context: &PolicyContext) -> Vec<Finding> {
+        if !context.production {
+            return vec![];
+        }
+
+        let plans: Vec<(&str, Option<&str>)> = match resource {
+            Resource::Configuration(config) => vec![
+                (
+                    "database.plan",
+                    config.database.as_ref().map(|d| d.plan.as_str()),
+                ),
+                ("redis.plan", config.redis.as_ref().map(|r| r.plan.as_str())),
+            ],
+            Resource::Service(template) => vec![(
+                "serviceDetails.plan",
+                template
+                    .service_details
+                    .as_ref()
+                    .and_then(|details| details.plan.as_ref())
+                    .map(|plan| plan.as_str()),
+            )],
+            Resource::PostgresUpdate(update) => vec![("plan", update.plan.as_deref())],
+            Resource::RedisUpdate(update) => vec![("plan", update.plan.as_deref())],
+            Resource::ServiceUpdate(update) => vec![(
+                "serviceDetails.plan",
+                update
+                    .service_details
+                    .as_ref()
+                    .and_then(|details| details.plan.as_ref())
+                    .map(|plan| plan.as_str()),
+            )],
+        };
+
+        plans
+            .into_iter()
+            .filter(|(_, plan)| *plan == Some("free"))
+            .map(|(field, _)| Finding::new(field, "must not be \"free\" in production"))
+            .collect()
+    }
+}
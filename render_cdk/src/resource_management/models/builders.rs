@@ -0,0 +1,428 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+// [render_cdk] modules.
+use super::disk::Disk;
+use super::enums::{Region, Runtime, ServicePlan, ServiceType, Toggle};
+use super::template::*;
+use crate::utils::validation::{Validate, ValidationErrors};
+
+/// Declares a service kind and its builder, with the setters every kind shares.
+macro_rules! service_builder {
+    ($(#[$meta:meta])* $kind:ident, $builder:ident, $type_:expr) => {
+        $(#[$meta])*
+        #[derive(Debug)]
+        pub struct $kind;
+
+        impl $kind {
+            pub fn builder() -> $builder {
+                $builder {
+                    template: Template {
+                        type_: $type_,
+                        ..Default::default()
+                    },
+                }
+            }
+        }
+
+        #[derive(Debug, Clone)]
+        pub struct $builder {
+            template: Template,
+        }
+
+        impl $builder {
+            pub fn name(mut self, name: &str) -> Self {
+                self.template.name = name.to_owned();
+                self
+            }
+
+            /// A GitHub/GitLab url i.e `https://github.com/username/reponame`.
+            pub fn repo(mut self, repo: &str) -> Self {
+                self.template.repo = repo.to_owned();
+                self
+            }
+
+            pub fn branch(mut self, branch: &str) -> Self {
+                self.template.branch = Some(branch.to_owned());
+                self
+            }
+
+            pub fn auto_deploy(mut self, enabled: bool) -> Self {
+                self.template.auto_deploy = Some(Toggle::from(enabled));
+                self
+            }
+
+            pub fn root_dir(mut self, root_dir: &str) -> Self {
+                self.template.root_dir = Some(root_dir.to_owned());
+                self
+            }
+
+            pub fn build_filter(mut self, paths: &[&str], ignored_paths: &[&str]) -> Self {
+                self.template.build_filter = Some(BuildFilter {
+                    paths: paths.iter().map(|path| path.to_string()).collect(),
+                    ignored_paths: ignored_paths.iter().map(|path| path.to_string()).collect(),
+                });
+                self
+            }
+
+            pub fn env_var(mut self, key: &str, value: &str) -> Self {
+                self.template.env_vars.push(EnvVar {
+                    key: key.to_owned(),
+                    value: Some(value.to_owned()),
+                    generate_value: false,
+                });
+                self
+            }
+
+            /// An env var Render sets to a random value.
+            pub fn generated_env_var(mut self, key: &str) -> Self {
+                self.template.env_vars.push(EnvVar {
+                    key: key.to_owned(),
+                    value: None,
+                    generate_value: true,
+                });
+                self
+            }
+
+            pub fn secret_file(mut self, name: &str, content: &str) -> Self {
+                self.template.secret_files.push(SecretFile {
+                    name: name.to_owned(),
+                    content: content.to_owned(),
+                });
+                self
+            }
+
+            /// Validates the template, see [Validate].
+            pub fn build(self) -> Result<Template, ValidationErrors> {
+                let errors = self.template.validate();
+                if errors.is_empty() {
+                    Ok(self.template)
+                } else {
+                    Err(errors)
+                }
+            }
+
+            fn details(&mut self) -> &mut ServiceDetails {
+                self.template
+                    .service_details
+                    .get_or_insert_with(ServiceDetails::default)
+            }
+
+            fn env_specific_details(&mut self) -> &mut EnvSpecificDetails {
+                self.details()
+                    .env_specific_details
+                    .get_or_insert_with(EnvSpecificDetails::default)
+            }
+        }
+    };
+}
+
+/// Setters of services running on an instance, i.e everything but static sites.
+macro_rules! runtime_setters {
+    ($builder:ident) => {
+        impl $builder {
+            pub fn region(mut self, region: Region) -> Self {
+                self.details().region = Some(region);
+                self
+            }
+
+            pub fn plan(mut self, plan: ServicePlan) -> Self {
+                self.details().plan = Some(plan);
+                self
+            }
+
+            pub fn runtime(mut self, runtime: Runtime) -> Self {
+                self.details().runtime = Some(runtime);
+                self
+            }
+
+            pub fn build_command(mut self, command: &str) -> Self {
+                self.env_specific_details().build_command = Some(command.to_owned());
+                self
+            }
+
+            pub fn start_command(mut self, command: &str) -> Self {
+                self.env_specific_details().start_command = Some(command.to_owned());
+                self
+            }
+
+            pub fn pre_deploy_command(mut self, command: &str) -> Self {
+                self.details().pre_deploy_command = Some(command.to_owned());
+                self
+            }
+
+            /// Builds `repo` with a Dockerfile, i.e `./Dockerfile` in the context `.`.
+            /// Sets the runtime to [Runtime::Docker].
+            pub fn dockerfile(mut self, dockerfile_path: &str, docker_context: &str) -> Self {
+                self.details().runtime = Some(Runtime::Docker);
+                let commands = self.env_specific_details();
+                commands.dockerfile_path = Some(dockerfile_path.to_owned());
+                commands.docker_context = Some(docker_context.to_owned());
+                self
+            }
+
+            /// Overrides the image's `CMD`.
+            pub fn docker_command(mut self, command: &str) -> Self {
+                self.env_specific_details().docker_command = Some(command.to_owned());
+                self
+            }
+
+            /// Credential used to pull private base images during the Docker build.
+            pub fn registry_credential(mut self, registry_credential_id: &str) -> Self {
+                self.env_specific_details().registry_credential_id =
+                    Some(registry_credential_id.to_owned());
+                self
+            }
+
+            /// Deploys a prebuilt image instead of building `repo`.
+            pub fn image(mut self, image: Image) -> Self {
+                self.template.image = Some(image);
+                self
+            }
+        }
+    };
+}
+
+/// Setters of long running services, which can be scaled.
+macro_rules! instance_setters {
+    ($builder:ident) => {
+        impl $builder {
+            pub fn num_instances(mut self, num_instances: i32) -> Self {
+                self.details().num_instances = Some(num_instances);
+                self
+            }
+
+            pub fn autoscaling(mut self, autoscaling: AutoScaling) -> Self {
+                self.template.autoscaling = Some(autoscaling);
+                self
+            }
+
+            /// A persistent disk, limiting the service to a single instance.
+            pub fn disk(mut self, name: &str, mount_path: &str, size_gb: u32) -> Self {
+                self.details().disk = Some(Disk::new(name, mount_path, size_gb));
+                self
+            }
+        }
+    };
+}
+
+service_builder!(
+    /// A static site, built from `repo` and served from `publish_path`.
+    StaticSite,
+    StaticSiteBuilder,
+    ServiceType::StaticSite
+);
+
+service_builder!(
+    /// A public web service.
+    WebService,
+    WebServiceBuilder,
+    ServiceType::WebService
+);
+
+service_builder!(
+    /// A web service only reachable from the private network.
+    PrivateService,
+    PrivateServiceBuilder,
+    ServiceType::PrivateService
+);
+
+service_builder!(
+    BackgroundWorker,
+    BackgroundWorkerBuilder,
+    ServiceType::BackgroundWorker
+);
+
+service_builder!(CronJob, CronJobBuilder, ServiceType::CronJob);
+
+runtime_setters!(WebServiceBuilder);
+runtime_setters!(PrivateServiceBuilder);
+runtime_setters!(BackgroundWorkerBuilder);
+runtime_setters!(CronJobBuilder);
+
+instance_setters!(WebServiceBuilder);
+instance_setters!(PrivateServiceBuilder);
+instance_setters!(BackgroundWorkerBuilder);
+
+impl StaticSiteBuilder {
+    pub fn build_command(mut self, command: &str) -> Self {
+        self.details().build_command = Some(command.to_owned());
+        self
+    }
+
+    /// The directory the build outputs the site to, i.e `./public`.
+    pub fn publish_path(mut self, path: &str) -> Self {
+        self.details().publish_path = Some(path.to_owned());
+        self
+    }
+
+    pub fn pull_request_previews(mut self, enabled: bool) -> Self {
+        self.details().pull_request_previews_enabled = Some(Toggle::from(enabled));
+        self
+    }
+
+    pub fn header(mut self, path: &str, name: &str, value: &str) -> Self {
+        self.details().headers.push(Header {
+            path: path.to_owned(),
+            name: name.to_owned(),
+            value: value.to_owned(),
+        });
+        self
+    }
+
+    /// A `redirect` or `rewrite` rule.
+    pub fn route(mut self, type_: &str, source: &str, destination: &str) -> Self {
+        let priority = self.details().routes.len() as u32;
+        self.details().routes.push(Route {
+            type_: type_.to_owned(),
+            source: source.to_owned(),
+            destination: destination.to_owned(),
+            priority,
+        });
+        self
+    }
+}
+
+impl WebServiceBuilder {
+    pub fn health_check_path(mut self, path: &str) -> Self {
+        self.template.health_check_path = Some(path.to_owned());
+        self
+    }
+
+    pub fn pull_request_previews(mut self, enabled: bool) -> Self {
+        self.details().pull_request_previews_enabled = Some(Toggle::from(enabled));
+        self
+    }
+}
+
+impl CronJobBuilder {
+    /// A cron expression i.e `0 3 * * *`, in UTC. Each run executes the start command.
+    pub fn schedule(mut self, schedule: &str) -> Self {
+        self.details().schedule = Some(schedule.to_owned());
+        self
+    }
+}
+
+impl PrivateServiceBuilder {
+    pub fn pull_request_previews(mut self, enabled: bool) -> Self {
+        self.details().pull_request_previews_enabled = Some(Toggle::from(enabled));
+        self
+    }
+}
+
+#[cfg(test)]
+mod builder_tests {
+    use super::*;
+
+    #[test]
+    fn test_build_templates() {
+        let site = StaticSite::builder()
+            .name("docs")
+            .repo("https://github.com/lexara-prime-ai/SAMPLE_STATIC_SITE")
+            .auto_deploy(true)
+            .publish_path("./public")
+            .route("rewrite", "/*", "/index.html")
+            .build()
+            .unwrap();
+
+        assert_eq!(site.type_, ServiceType::StaticSite);
+        assert_eq!(site.auto_deploy, Some(Toggle::Yes));
+        let details = site.service_details.unwrap();
+        assert_eq!(details.publish_path.as_deref(), Some("./public"));
+        assert_eq!(details.routes[0].priority, 0);
+
+        let api = WebService::builder()
+            .name("api")
+            .repo("https://github.com/lexara-prime-ai/SAMPLE_WEB_SERVICE")
+            .region(Region::Oregon)
+            .runtime(Runtime::Node)
+            .build_command("yarn")
+            .start_command("npm start")
+            .num_instances(2)
+            .build()
+            .unwrap();
+
+        let details = api.service_details.unwrap();
+        let commands = details.env_specific_details.unwrap();
+        assert_eq!(commands.build_command.as_deref(), Some("yarn"));
+        assert_eq!(commands.start_command.as_deref(), Some("npm start"));
+        assert_eq!(details.num_instances, Some(2));
+    }
+
+    #[test]
+    fn test_build_validates() {
+        let errors = BackgroundWorker::builder()
+            .name("jobs")
+            .repo("git@github.com:lexara-prime-ai/jobs.git")
+            .num_instances(0)
+            .build()
+            .unwrap_err();
+
+        let fields: Vec<&str> = errors.0.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["repo", "serviceDetails.numInstances"]);
+    }
+
+    #[test]
+    fn test_cron_job_schedule() {
+        let errors = CronJob::builder()
+            .name("report")
+            .repo("https://github.com/lexara-prime-ai/SAMPLE_WEB_SERVICE")
+            .schedule("every night")
+            .build()
+            .unwrap_err();
+
+        let fields: Vec<&str> = errors.0.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "serviceDetails.schedule",
+                "serviceDetails.envSpecificDetails.startCommand"
+            ]
+        );
+
+        let cron_job = CronJob::builder()
+            .name("report")
+            .repo("https://github.com/lexara-prime-ai/SAMPLE_WEB_SERVICE")
+            .schedule("*/15 * * * MON-FRI")
+            .start_command("npm run report")
+            .build();
+        assert!(cron_job.is_ok());
+    }
+
+    #[test]
+    fn test_private_docker_service() {
+        let service = PrivateService::builder()
+            .name("internal-api")
+            .repo("https://github.com/lexara-prime-ai/SAMPLE_WEB_SERVICE")
+            .dockerfile("./api/Dockerfile", "./api")
+            .docker_command("./server --port 10000")
+            .registry_credential("rcr-mockdockerhub000000")
+            .build()
+            .unwrap();
+
+        assert_eq!(service.type_, ServiceType::PrivateService);
+        let details = service.service_details.unwrap();
+        assert_eq!(details.runtime, Some(Runtime::Docker));
+        let commands = details.env_specific_details.unwrap();
+        assert_eq!(
+            commands.dockerfile_path.as_deref(),
+            Some("./api/Dockerfile")
+        );
+        assert_eq!(
+            commands.registry_credential_id.as_deref(),
+            Some("rcr-mockdockerhub000000")
+        );
+
+        // Docker settings are rejected on other runtimes.
+        let errors = WebService::builder()
+            .name("api")
+            .repo("https://github.com/lexara-prime-ai/SAMPLE_WEB_SERVICE")
+            .docker_command("./server")
+            .runtime(Runtime::Node)
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            errors.0[0].field,
+            "serviceDetails.envSpecificDetails.dockerCommand"
+        );
+    }
+}
@@ -2,19 +2,27 @@
 #![allow(unused)]
 #![allow(non_snake_case)]
 // [JSON] parsing.
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+// [render_cdk] modules.
+use super::enums::{MaxmemoryPolicy, Region};
+
 // [DEBUG] utils.
 use colored::Colorize;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct CacheConf {
     pub name: Option<String>,
     pub plan: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maxmemoryPolicy: Option<MaxmemoryPolicy>,
     pub cidrBlocks: Vec<RedisCidrAllowList>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct RedisCidrAllowList {
     pub cidrBlock: String,
     pub description: String,
@@ -0,0 +1,149 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+// [JSON] parsing.
+use serde::{Deserialize, Serialize};
+
+// [render_cdk] modules.
+use super::enums::ServiceType;
+use crate::cost_management::cost_estimator::CostEstimate;
+
+use std::fmt;
+
+/// How `deploy_configuration_with_options` reacts to a resource failing.
+#[derive(Debug, Clone)]
+pub struct DeploymentOptions {
+    /// Skip the remaining resources once one fails, rather than attempting all of them.
+    pub fail_fast: bool,
+    /// Delete the resources created by this deployment once one fails.
+    pub rollback: bool,
+    /// The owner of the API key when `None`.
+    pub owner_id: Option<String>,
+}
+
+impl Default for DeploymentOptions {
+    fn default() -> Self {
+        Self {
+            fail_fast: true,
+            rollback: false,
+            owner_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(tag = "kind", content = "type", rename_all = "snake_case")]
+pub enum ResourceKind {
+    Postgres,
+    Redis,
+    Service(ServiceType),
+}
+
+impl fmt::Display for ResourceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResourceKind::Postgres => f.write_str("postgres"),
+            ResourceKind::Redis => f.write_str("redis"),
+            ResourceKind::Service(service_type) => write!(f, "{}", service_type),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploymentStatus {
+    Created,
+    Failed,
+    /// Not attempted, since an earlier resource failed with [DeploymentOptions::fail_fast].
+    Skipped,
+    /// Created, then deleted since another resource failed with [DeploymentOptions::rollback].
+    RolledBack,
+    /// Created, but deleting it failed, see [ResourceOutcome::error].
+    RollbackFailed,
+}
+
+impl fmt::Display for DeploymentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            DeploymentStatus::Created => "CREATED",
+            DeploymentStatus::Failed => "FAILED",
+            DeploymentStatus::Skipped => "SKIPPED",
+            DeploymentStatus::RolledBack => "ROLLED BACK",
+            DeploymentStatus::RollbackFailed => "ROLLBACK FAILED",
+        };
+        f.write_str(status)
+    }
+}
+
+/// What happened to a single resource of a configuration.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ResourceOutcome {
+    pub kind: ResourceKind,
+    pub name: String,
+    pub status: DeploymentStatus,
+    pub id: Option<String>,
+    pub dashboard_url: Option<String>,
+    /// Time spent creating the resource.
+    pub duration_ms: u64,
+    pub error: Option<String>,
+}
+
+/// The outcome of every resource of a configuration, in deployment order.
+///
+/// Deployments with a failed resource return the report as their error, so it can be recovered with
+/// `error.downcast_ref::<DeploymentReport>()`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct DeploymentReport {
+    pub resources: Vec<ResourceOutcome>,
+    pub duration_ms: u64,
+    /// The estimated monthly cost of the configuration, computed before deploying it.
+    pub cost: Option<CostEstimate>,
+}
+
+impl DeploymentReport {
+    pub fn is_success(&self) -> bool {
+        self.resources
+            .iter()
+            .all(|resource| resource.status == DeploymentStatus::Created)
+    }
+
+    /// The resources with the specified status.
+    pub fn with_status(&self, status: DeploymentStatus) -> Vec<&ResourceOutcome> {
+        self.resources
+            .iter()
+            .filter(|resource| resource.status == status)
+            .collect()
+    }
+}
+
+impl fmt::Display for DeploymentReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Deployed {} of {} resource(s) in {}ms:",
+            self.with_status(DeploymentStatus::Created).len(),
+            self.resources.len(),
+            self.duration_ms
+        )?;
+
+        for resource in &self.resources {
+            write!(
+                f,
+                "  [{}] {} {:?} ({}ms)",
+                resource.status, resource.kind, resource.name, resource.duration_ms
+            )?;
+            if let Some(url) = &resource.dashboard_url {
+                write!(f, " -> {}", url)?;
+            }
+            if let Some(error) = &resource.error {
+                write!(f, " :: {}", error)?;
+            }
+            writeln!(f)?;
+        }
+        if let Some(cost) = &self.cost {
+            write!(f, "{}", cost)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for DeploymentReport {}
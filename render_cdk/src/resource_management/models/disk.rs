@@ -0,0 +1,52 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+// [JSON] parsing.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A persistent disk, mounted into a single service instance.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct Disk {
+    pub name: String,
+
+    /// Absolute path the disk is mounted at, i.e `/var/lib/data`.
+    #[serde(rename = "mountPath")]
+    pub mount_path: String,
+
+    /// Disks can grow but not shrink.
+    #[serde(rename = "sizeGB")]
+    pub size_gb: u32,
+}
+
+impl Disk {
+    pub fn new(name: &str, mount_path: &str, size_gb: u32) -> Self {
+        Self {
+            name: name.to_owned(),
+            mount_path: mount_path.to_owned(),
+            size_gb,
+        }
+    }
+}
+
+/// Payload attaching a [Disk] to an existing service.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DiskConf {
+    #[serde(flatten)]
+    pub disk: Disk,
+
+    #[serde(rename = "serviceId")]
+    pub service_id: String,
+}
+
+/// Payload changing a disk, any `None` is left as is.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct DiskUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(rename = "mountPath", skip_serializing_if = "Option::is_none")]
+    pub mount_path: Option<String>,
+
+    #[serde(rename = "sizeGB", skip_serializing_if = "Option::is_none")]
+    pub size_gb: Option<u32>,
+}
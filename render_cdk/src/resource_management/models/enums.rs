@@ -0,0 +1,239 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+// [JSON] parsing.
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use std::borrow::Cow;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// Declares a string backed enum, values Render doesn't know yet are kept in `Other`.
+///
+/// The variants (de)serialize to their API value i.e `ServiceType::WebService` <-> `"web_service"`,
+/// aliases (i.e blueprint names) are only read. The JSON Schema lists every accepted value so editors flag typos.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident { $($(#[$variant_meta:meta])* $variant:ident => $value:literal $(| $alias:literal)*),+ $(,)? }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)+
+            /// A value this version of the crate doesn't know, rejected by validation unless unknown values are allowed.
+            Other(String),
+        }
+
+        impl $name {
+            /// The values Render accepts.
+            pub const VALUES: &'static [&'static str] = &[$($value),+];
+
+            /// [Self::VALUES] and the aliases read as them.
+            pub const ACCEPTED: &'static [&'static str] = &[$($value $(, $alias)*),+];
+
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)+
+                    $name::Other(value) => value,
+                }
+            }
+
+            pub fn is_known(&self) -> bool {
+                !matches!(self, $name::Other(_))
+            }
+        }
+
+        /// Blank until set, so a default [Template] still fails validation.
+        impl Default for $name {
+            fn default() -> Self {
+                $name::Other(String::new())
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value $(| $alias)* => $name::$variant,)+
+                    _ => $name::Other(value.to_owned()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                $name::from(value.as_str())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Ok($name::from(value))
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.as_str() == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.as_str() == *other
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                String::deserialize(deserializer).map($name::from)
+            }
+        }
+
+        impl JsonSchema for $name {
+            fn schema_name() -> Cow<'static, str> {
+                stringify!($name).into()
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                json_schema!({
+                    "type": "string",
+                    "enum": $name::ACCEPTED,
+                })
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// The kind of service, sent as `type`. Blueprint names i.e `pserv` are accepted too.
+    ServiceType {
+        StaticSite => "static_site",
+        WebService => "web_service" | "web",
+        PrivateService => "private_service" | "pserv",
+        BackgroundWorker => "background_worker" | "worker",
+        CronJob => "cron_job" | "cron",
+    }
+}
+
+string_enum! {
+    /// The values of `autoDeploy` and `pullRequestPreviewsEnabled`.
+    Toggle {
+        Yes => "yes",
+        No => "no",
+    }
+}
+
+string_enum! {
+    Region {
+        Oregon => "oregon",
+        Ohio => "ohio",
+        Virginia => "virginia",
+        Frankfurt => "frankfurt",
+        Singapore => "singapore",
+    }
+}
+
+string_enum! {
+    /// Instance types of web services, private services, workers and cron jobs.
+    ServicePlan {
+        Free => "free",
+        Starter => "starter",
+        StarterPlus => "starter_plus",
+        Standard => "standard",
+        StandardPlus => "standard_plus",
+        Pro => "pro",
+        ProPlus => "pro_plus",
+        ProMax => "pro_max",
+        ProUltra => "pro_ultra",
+    }
+}
+
+string_enum! {
+    Runtime {
+        Docker => "docker",
+        Elixir => "elixir",
+        Go => "go",
+        Image => "image",
+        Node => "node",
+        Python => "python",
+        Ruby => "ruby",
+        Rust => "rust",
+    }
+}
+
+string_enum! {
+    /// The registry a credential authenticates with, sent as `registry`.
+    Registry {
+        DockerHub => "DOCKER",
+        Github => "GITHUB",
+        Gitlab => "GITLAB",
+        GoogleArtifact => "GOOGLE_ARTIFACT",
+        AwsEcr => "AWS_ECR",
+    }
+}
+
+string_enum! {
+    /// How Redis evicts keys once it reaches its memory limit, sent as `maxmemoryPolicy`.
+    MaxmemoryPolicy {
+        AllKeysLru => "allkeys_lru",
+        AllKeysLfu => "allkeys_lfu",
+        AllKeysRandom => "allkeys_random",
+        VolatileLru => "volatile_lru",
+        VolatileLfu => "volatile_lfu",
+        VolatileRandom => "volatile_random",
+        VolatileTtl => "volatile_ttl",
+        NoEviction => "noeviction",
+    }
+}
+
+impl From<bool> for Toggle {
+    fn from(value: bool) -> Self {
+        if value {
+            Toggle::Yes
+        } else {
+            Toggle::No
+        }
+    }
+}
+
+#[cfg(test)]
+mod enum_tests {
+    use super::*;
+
+    #[test]
+    fn test_string_enums() {
+        assert_eq!(ServiceType::from("web_service"), ServiceType::WebService);
+        assert_eq!(ServiceType::from("pserv"), ServiceType::PrivateService);
+        assert_eq!(ServiceType::PrivateService.as_str(), "private_service");
+        assert_eq!(
+            serde_json::to_string(&ServiceType::StaticSite).unwrap(),
+            "\"static_site\""
+        );
+
+        // Unknown values round trip through `Other`.
+        let typo: ServiceType = serde_json::from_str("\"statik\"").unwrap();
+        assert_eq!(typo, ServiceType::Other("statik".to_owned()));
+        assert!(!typo.is_known());
+        assert_eq!(serde_json::to_string(&typo).unwrap(), "\"statik\"");
+
+        assert_eq!(Region::Frankfurt.to_string(), "frankfurt");
+        assert_eq!(Toggle::from(true), "yes");
+        assert!(ServicePlan::VALUES.contains(&"starter_plus"));
+    }
+}
@@ -0,0 +1,97 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+#![allow(non_snake_case)]
+// [JSON] parsing.
+use serde::{Deserialize, Serialize};
+
+use chrono::{DateTime, Utc};
+use std::fmt;
+
+/// Filters of `LogManager::list_logs` and `tail_logs`, unset filters match every entry.
+#[derive(Debug, Default, Clone)]
+pub struct LogFilters {
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    /// i.e `error`, `warning` or `info`.
+    pub levels: Vec<String>,
+    /// Matches entries containing any of the values.
+    pub text: Vec<String>,
+    /// Instance ids, for services running several instances.
+    pub instances: Vec<String>,
+    /// i.e `app`, `request` or `build`.
+    pub types: Vec<String>,
+    /// Narrows the time range to a deploy of a service, unless `start_time`/`end_time` are set.
+    pub deploy_id: Option<String>,
+}
+
+impl LogFilters {
+    /// The filters as query parameters, `deploy_id` is resolved to a time range beforehand.
+    pub fn query(&self) -> String {
+        let mut query = url::form_urlencoded::Serializer::new(String::new());
+        if let Some(start_time) = &self.start_time {
+            query.append_pair("startTime", &start_time.to_rfc3339());
+        }
+        if let Some(end_time) = &self.end_time {
+            query.append_pair("endTime", &end_time.to_rfc3339());
+        }
+        for (param, values) in [
+            ("level", &self.levels),
+            ("text", &self.text),
+            ("instance", &self.instances),
+            ("type", &self.types),
+        ] {
+            if !values.is_empty() {
+                query.append_pair(param, &values.join(","));
+            }
+        }
+        query.finish()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LogLabel {
+    pub name: String,
+    pub value: String,
+}
+
+/// A single log line.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LogEntry {
+    pub id: String,
+    pub timestamp: DateTime<Utc>,
+    pub message: String,
+    /// i.e `resource`, `instance`, `level` and `type`.
+    #[serde(default)]
+    pub labels: Vec<LogLabel>,
+}
+
+impl LogEntry {
+    pub fn label(&self, name: &str) -> Option<&str> {
+        self.labels
+            .iter()
+            .find(|label| label.name == name)
+            .map(|label| label.value.as_str())
+    }
+}
+
+impl fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} [{}] {}",
+            self.timestamp.to_rfc3339(),
+            self.label("level").unwrap_or("-"),
+            self.message
+        )
+    }
+}
+
+/// A page of log entries, the next page starts or ends at `nextStartTime`/`nextEndTime`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct LogPage {
+    pub hasMore: bool,
+    pub nextStartTime: Option<DateTime<Utc>>,
+    pub nextEndTime: Option<DateTime<Utc>>,
+    pub logs: Vec<LogEntry>,
+}
@@ -1,6 +1,12 @@
+pub mod builders;
 pub mod caching;
+pub mod deployment;
+pub mod disk;
+pub mod enums;
+pub mod logs;
 pub mod postgres;
 pub mod prelude;
 pub mod redis;
+pub mod registry;
 pub mod storage;
 pub mod template;
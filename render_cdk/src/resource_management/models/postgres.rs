@@ -5,7 +5,10 @@
 use serde::{Deserialize, Serialize};
 
 // [render_cdk] modules.
-use super::storage::PostgresCidrAllowList;
+use super::enums::Region;
+use super::storage::{DatabaseConf, PostgresCidrAllowList};
+
+use std::fmt;
 
 // [DEBUG] utils.
 use colored::Colorize;
@@ -18,6 +21,144 @@ pub struct PostgresConf {
     pub plan: String,
     pub version: String,
     pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
     pub ownerId: String,
     pub ipAllowList: Option<Vec<PostgresCidrAllowList>>,
 }
+
+/// A Postgres instance to create, without a configuration file.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct PostgresSpec {
+    /// Generated when `None`.
+    pub name: Option<String>,
+    pub plan: String,
+    pub version: String,
+    pub region: Option<Region>,
+    pub databaseName: Option<String>,
+    pub databaseUser: Option<String>,
+    pub enableHighAvailability: bool,
+    /// An empty list blocks all external access.
+    pub ipAllowList: Vec<PostgresCidrAllowList>,
+    /// The owner of the API key when `None`.
+    pub ownerId: Option<String>,
+}
+
+impl PostgresSpec {
+    pub fn new(name: &str, plan: &str, version: &str) -> Self {
+        Self {
+            name: Some(name.to_owned()),
+            plan: plan.to_owned(),
+            version: version.to_owned(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<DatabaseConf> for PostgresSpec {
+    fn from(database: DatabaseConf) -> Self {
+        Self {
+            name: database.name,
+            plan: database.plan,
+            version: database.version,
+            region: database.region,
+            databaseName: database.databaseName,
+            databaseUser: database.databaseUser,
+            enableHighAvailability: database.enableHighAvailability,
+            ipAllowList: database.cidrBlocks,
+            ownerId: None,
+        }
+    }
+}
+
+/// The `[database]` section the spec would be written as, for validation and policies.
+impl From<PostgresSpec> for DatabaseConf {
+    fn from(spec: PostgresSpec) -> Self {
+        Self {
+            databaseName: spec.databaseName,
+            databaseUser: spec.databaseUser,
+            enableHighAvailability: spec.enableHighAvailability,
+            plan: spec.plan,
+            version: spec.version,
+            name: spec.name,
+            region: spec.region,
+            cidrBlocks: spec.ipAllowList,
+        }
+    }
+}
+
+/// A Postgres instance, as returned by the API.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PostgresInstance {
+    pub id: String,
+    pub name: String,
+    pub plan: String,
+    pub version: String,
+    pub region: Region,
+    /// i.e `creating`, `available` or `suspended`.
+    pub status: String,
+    pub databaseName: String,
+    pub databaseUser: String,
+    pub highAvailabilityEnabled: bool,
+    pub dashboardUrl: String,
+    pub createdAt: String,
+}
+
+/// Payload changing a Postgres instance, any `None` is left as is.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct PostgresUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<String>,
+    /// Flexible plans only, storage can grow but not shrink.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diskSizeGB: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enableHighAvailability: Option<bool>,
+    /// Replaces the whole list, an empty list blocks all external access.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ipAllowList: Option<Vec<PostgresCidrAllowList>>,
+    /// Replaces the whole list, replicas left out are deleted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub readReplicas: Option<Vec<ReadReplica>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ReadReplica {
+    pub name: String,
+}
+
+/// How to connect to a Postgres instance.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct PostgresConnectionInfo {
+    pub password: String,
+    /// Reachable from services in the same region.
+    pub internalConnectionString: String,
+    /// Reachable from the addresses in the IP allow list.
+    pub externalConnectionString: String,
+    pub psqlCommand: String,
+}
+
+/// Keeps the password out of logs.
+impl fmt::Debug for PostgresConnectionInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PostgresConnectionInfo")
+            .field("password", &"<redacted>")
+            .field("internalConnectionString", &"<redacted>")
+            .field("externalConnectionString", &"<redacted>")
+            .field("psqlCommand", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Payload recovering a Postgres instance into a new one, as it was at `restoreTime`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PostgresRecovery {
+    /// RFC 3339 timestamp within the recovery window, see `get_postgres_recovery_info`.
+    pub restoreTime: String,
+    /// The name of the new instance, Render derives one when `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restoreName: Option<String>,
+}
@@ -1,2 +1,10 @@
+pub use crate::resource_management::models::builders::*;
+pub use crate::resource_management::models::deployment::*;
+pub use crate::resource_management::models::disk::*;
+pub use crate::resource_management::models::enums::*;
+pub use crate::resource_management::models::logs::*;
+pub use crate::resource_management::models::postgres::*;
+pub use crate::resource_management::models::redis::*;
+pub use crate::resource_management::models::registry::*;
 pub use crate::resource_management::models::template::*;
 pub use crate::resource_management::models::*;
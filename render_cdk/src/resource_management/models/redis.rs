@@ -5,7 +5,10 @@
 use serde::{Deserialize, Serialize};
 
 // [render_cdk] modules.
-use super::caching::RedisCidrAllowList;
+use super::caching::{CacheConf, RedisCidrAllowList};
+use super::enums::{MaxmemoryPolicy, Region};
+
+use std::fmt;
 
 // [DEBUG] utils.
 use colored::Colorize;
@@ -14,6 +17,136 @@ use colored::Colorize;
 pub struct RedisConf {
     pub name: Option<String>,
     pub plan: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxmemoryPolicy: Option<MaxmemoryPolicy>,
     pub ownerId: String,
     pub ipAllowList: Option<Vec<RedisCidrAllowList>>,
 }
+
+/// A Redis instance to create, without a configuration file.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct RedisSpec {
+    /// Generated when `None`.
+    pub name: Option<String>,
+    /// Defaults to `starter` when blank.
+    pub plan: String,
+    pub region: Option<Region>,
+    pub maxmemoryPolicy: Option<MaxmemoryPolicy>,
+    /// An empty list blocks all external access.
+    pub ipAllowList: Vec<RedisCidrAllowList>,
+    /// The owner of the API key when `None`.
+    pub ownerId: Option<String>,
+}
+
+impl RedisSpec {
+    pub fn new(name: &str, plan: &str) -> Self {
+        Self {
+            name: Some(name.to_owned()),
+            plan: plan.to_owned(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<CacheConf> for RedisSpec {
+    fn from(redis: CacheConf) -> Self {
+        Self {
+            name: redis.name,
+            plan: redis.plan,
+            region: redis.region,
+            maxmemoryPolicy: redis.maxmemoryPolicy,
+            ipAllowList: redis.cidrBlocks,
+            ownerId: None,
+        }
+    }
+}
+
+/// The `[redis]` section the spec would be written as, for validation and policies.
+impl From<RedisSpec> for CacheConf {
+    fn from(spec: RedisSpec) -> Self {
+        Self {
+            name: spec.name,
+            plan: spec.plan,
+            region: spec.region,
+            maxmemoryPolicy: spec.maxmemoryPolicy,
+            cidrBlocks: spec.ipAllowList,
+        }
+    }
+}
+
+/// A Redis instance, as returned by the API.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RedisInstance {
+    pub id: String,
+    pub name: String,
+    pub plan: String,
+    pub region: Region,
+    /// i.e `creating`, `available` or `suspended`.
+    pub status: String,
+    pub maxmemoryPolicy: Option<MaxmemoryPolicy>,
+    pub dashboardUrl: String,
+    pub createdAt: String,
+}
+
+/// Filters of `RedisManager::list_redis_instances`, unset filters match every instance.
+#[derive(Debug, Default, Clone)]
+pub struct RedisFilters {
+    pub names: Vec<String>,
+    pub region: Option<Region>,
+    pub owner_id: Option<String>,
+}
+
+impl RedisFilters {
+    /// The filters as query parameters, i.e `name=cache&region=oregon`.
+    pub fn query(&self) -> String {
+        let mut query = url::form_urlencoded::Serializer::new(String::new());
+        if !self.names.is_empty() {
+            query.append_pair("name", &self.names.join(","));
+        }
+        if let Some(region) = &self.region {
+            query.append_pair("region", region.as_str());
+        }
+        if let Some(owner_id) = &self.owner_id {
+            query.append_pair("ownerId", owner_id);
+        }
+        query.finish()
+    }
+}
+
+/// Payload changing a Redis instance, any `None` is left as is.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct RedisUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maxmemoryPolicy: Option<MaxmemoryPolicy>,
+    /// Replaces the whole list, an empty list blocks all external access.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ipAllowList: Option<Vec<RedisCidrAllowList>>,
+}
+
+/// How to connect to a Redis instance.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct RedisConnectionInfo {
+    /// Reachable from services in the same region.
+    pub internalConnectionString: String,
+    /// Reachable from the addresses in the IP allow list.
+    pub externalConnectionString: String,
+    pub redisCLICommand: String,
+}
+
+/// Keeps the password out of logs.
+impl fmt::Debug for RedisConnectionInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedisConnectionInfo")
+            .field("internalConnectionString", &"<redacted>")
+            .field("externalConnectionString", &"<redacted>")
+            .field("redisCLICommand", &"<redacted>")
+            .finish()
+    }
+}
@@ -0,0 +1,52 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+// [JSON] parsing.
+use serde::{Deserialize, Serialize};
+
+use std::fmt;
+
+// [render_cdk] modules.
+use super::enums::Registry;
+
+/// Ids of registry credentials start with `rcr-`, anything else is looked up by name.
+pub const REGISTRY_CREDENTIAL_ID_PREFIX: &str = "rcr-";
+
+/// Credentials used to pull images from a private registry, i.e a Docker Hub access token.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct RegistryCredentialConf {
+    pub registry: Registry,
+    pub name: String,
+    pub username: String,
+
+    #[serde(rename = "authToken")]
+    pub auth_token: String,
+
+    /// Defaults to the owner of the API key.
+    #[serde(rename = "ownerId", skip_serializing_if = "Option::is_none")]
+    pub owner_id: Option<String>,
+}
+
+impl RegistryCredentialConf {
+    pub fn new(registry: Registry, name: &str, username: &str, auth_token: &str) -> Self {
+        Self {
+            registry,
+            name: name.to_owned(),
+            username: username.to_owned(),
+            auth_token: auth_token.to_owned(),
+            owner_id: None,
+        }
+    }
+}
+
+/// Keeps the token out of logs.
+impl fmt::Debug for RegistryCredentialConf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegistryCredentialConf")
+            .field("registry", &self.registry)
+            .field("name", &self.name)
+            .field("username", &self.username)
+            .field("auth_token", &"<redacted>")
+            .field("owner_id", &self.owner_id)
+            .finish()
+    }
+}
@@ -2,12 +2,16 @@
 #![allow(unused)]
 #![allow(non_snake_case)]
 // [JSON] parsing.
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+// [render_cdk] modules.
+use super::enums::Region;
+
 // [DEBUG] utils.
 use colored::Colorize;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct DatabaseConf {
     pub databaseName: Option<String>,
     pub databaseUser: Option<String>,
@@ -15,10 +19,12 @@ pub struct DatabaseConf {
     pub plan: String,
     pub version: String,
     pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
     pub cidrBlocks: Vec<PostgresCidrAllowList>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct PostgresCidrAllowList {
     pub cidrBlock: String,
     pub description: String,
@@ -1,12 +1,17 @@
 #![allow(missing_docs)]
 #![allow(unused)]
 // [JSON] parsing.
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+// [render_cdk] modules.
+use super::disk::Disk;
+use super::enums::{Region, Runtime, ServicePlan, ServiceType, Toggle};
+
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
 pub struct Base {
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ServiceType,
     pub name: String,
     pub repo: String,
 
@@ -15,7 +20,7 @@ pub struct Base {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "autoDeploy")]
-    pub auto_deploy: Option<String>,
+    pub auto_deploy: Option<Toggle>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub branch: Option<String>,
@@ -51,16 +56,17 @@ pub struct Base {
     pub autoscaling: Option<AutoScaling>,
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct Template {
     #[serde(rename = "type")]
-    pub type_: String,
+    pub type_: ServiceType,
     pub name: String,
+    #[serde(default)]
     pub repo: String,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "autoDeploy")]
-    pub auto_deploy: Option<String>,
+    pub auto_deploy: Option<Toggle>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub branch: Option<String>,
@@ -76,11 +82,11 @@ pub struct Template {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub root_dir: Option<String>,
 
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     #[serde(rename = "envVars")]
     pub env_vars: Vec<EnvVar>,
 
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     #[serde(rename = "secretFiles")]
     pub secret_files: Vec<SecretFile>,
 
@@ -96,11 +102,12 @@ pub struct Template {
     pub autoscaling: Option<AutoScaling>,
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct Image {
     #[serde(rename = "ownerId")]
     pub owner_id: String,
 
+    /// An id (`rcr-...`) or the name of a registry credential, resolved when the service is created.
     #[serde(rename = "registryCredentialId")]
     pub registry_credential_id: String,
 
@@ -108,7 +115,7 @@ pub struct Image {
     pub image_path: String,
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct BuildFilter {
     pub paths: Vec<String>,
 
@@ -116,27 +123,27 @@ pub struct BuildFilter {
     pub ignored_paths: Vec<String>,
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct EnvVar {
     pub key: String,
     pub value: Option<String>,
 
-    #[serde(rename = "generateValue")]
+    #[serde(default, rename = "generateValue")]
     pub generate_value: bool,
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct SecretFile {
     pub name: String,
     pub content: String,
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ServiceDetails {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub region: Option<String>,
+    pub region: Option<Region>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub plan: Option<String>,
+    pub plan: Option<ServicePlan>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "buildCommand")]
@@ -146,7 +153,7 @@ pub struct ServiceDetails {
     #[serde(rename = "preDeployCommand")]
     pub pre_deploy_command: Option<String>,
 
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub headers: Vec<Header>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -155,16 +162,24 @@ pub struct ServiceDetails {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "pullRequestPreviewsEnabled")]
-    pub pull_request_previews_enabled: Option<String>,
+    pub pull_request_previews_enabled: Option<Toggle>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runtime: Option<Runtime>,
 
+    /// Cron expression of a cron job, i.e `0 3 * * *`. The job runs `startCommand`.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub runtime: Option<String>,
+    pub schedule: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "numInstances")]
     pub num_instances: Option<i32>,
 
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    /// Web services, private services and background workers only. Services with a disk can't scale.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disk: Option<Disk>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub routes: Vec<Route>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -172,7 +187,36 @@ pub struct ServiceDetails {
     pub env_specific_details: Option<EnvSpecificDetails>,
 }
 
+/// The fields to change on an existing service, any `None` is left as is.
 #[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct ServiceUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "autoDeploy")]
+    pub auto_deploy: Option<Toggle>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<Image>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "rootDir")]
+    pub root_dir: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "serviceDetails")]
+    pub service_details: Option<ServiceDetails>,
+}
+
+/// Build and start settings, which apply depend on the runtime.
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct EnvSpecificDetails {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "buildCommand")]
@@ -181,42 +225,62 @@ pub struct EnvSpecificDetails {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "startCommand")]
     pub start_command: Option<String>,
+
+    /// Overrides the image's `CMD`, for the docker runtime.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "dockerCommand")]
+    pub docker_command: Option<String>,
+
+    /// Relative to the repo root, i.e `./Dockerfile`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "dockerfilePath")]
+    pub dockerfile_path: Option<String>,
+
+    /// The build context, relative to the repo root.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "dockerContext")]
+    pub docker_context: Option<String>,
+
+    /// Credential used to pull private base images during the build, an id or a name like [Image::registry_credential_id].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "registryCredentialId")]
+    pub registry_credential_id: Option<String>,
 }
 
 // Autoscaling properties.
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct AutoScaling {
-    min: u32,
-    max: u32,
-    criteria: Option<Criteria>,
+    pub min: u32,
+    pub max: u32,
+    pub criteria: Option<Criteria>,
 }
 
 // Autoscaling criteria.
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct Criteria {
     pub cpu: Option<Cpu>,
     pub memory: Option<Memory>,
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct Cpu {
     pub percentage: u32,
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct Memory {
     pub percentage: u32,
 }
 
 // Additional configuration i.e header, route etc.
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct Header {
     pub path: String,
     pub name: String,
     pub value: String,
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct Route {
     #[serde(rename = "type")]
     pub type_: String,
@@ -232,19 +296,22 @@ mod model_tests {
     #[test]
     fn test_template_model() {
         let service_details = ServiceDetails {
-            region: Some("oregon".to_owned()),
-            plan: Some("starter".to_owned()),
+            region: Some(Region::Oregon),
+            plan: Some(ServicePlan::Starter),
             build_command: Some("yarn".to_owned()),
             pre_deploy_command: None,
             headers: vec![],
             publish_path: Some("./".to_owned()),
-            pull_request_previews_enabled: Some("yes".to_owned()),
-            runtime: Some("node".to_owned()),
+            pull_request_previews_enabled: Some(Toggle::Yes),
+            runtime: Some(Runtime::Node),
+            schedule: None,
             num_instances: Some(1),
+            disk: None,
             routes: vec![],
             env_specific_details: Some(EnvSpecificDetails {
                 build_command: Some("yarn".to_owned()),
                 start_command: Some("npm start".to_owned()),
+                ..Default::default()
             }),
         };
 
@@ -264,10 +331,10 @@ mod model_tests {
         };
 
         let template = Template {
-            type_: "web_service".to_owned(),
+            type_: ServiceType::WebService,
             name: "test_web_service".to_owned(),
             repo: "https://github.com/<username>/<repo>".to_owned(),
-            auto_deploy: Some("yes".to_owned()),
+            auto_deploy: Some(Toggle::Yes),
             branch: Some("master".to_owned()),
             image: Some(image),
             build_filter: None,
@@ -279,9 +346,9 @@ mod model_tests {
             autoscaling: Some(autoscaling),
         };
 
-        assert_eq!(template.type_, "web_service".to_owned());
+        assert_eq!(template.type_, ServiceType::WebService);
         assert_eq!(template.name, "test_web_service".to_owned());
-        assert_eq!(template.auto_deploy, Some("yes".to_owned()));
+        assert_eq!(template.auto_deploy, Some(Toggle::Yes));
         assert_eq!(
             template.repo,
             "https://github.com/<username>/<repo>".to_owned()
@@ -1,2 +1,8 @@
 pub use crate::resource_management::models::*;
+pub use crate::resource_management::services::disk_manager::*;
+pub use crate::resource_management::services::job_manager::*;
+pub use crate::resource_management::services::log_manager::*;
+pub use crate::resource_management::services::postgres_manager::*;
+pub use crate::resource_management::services::redis_manager::*;
+pub use crate::resource_management::services::registry_manager::*;
 pub use crate::resource_management::services::service_manager::*;
@@ -0,0 +1,440 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+#![allow(non_snake_case)]
+// [JSON] parsing.
+use serde_json::{json, Value};
+
+// Idiomatic [ERROR] handling.
+use anyhow::{Context, Error, Ok, Result};
+
+// HTTP.
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+
+// [render_cdk] modules.
+use crate::resource_management::models::prelude::*;
+use crate::resource_management::services::service_manager::{
+    service_id, ServiceManager, ServiceManagerOperations,
+};
+use crate::state_management::state::State;
+use crate::utils::validation::Validate;
+
+// [DEBUG] utils.
+use crate::logger::prelude::*;
+use crate::utils::stringify::Stringify;
+use crate::{
+    create_delete_request, create_get_request, create_patch_request, create_post_request,
+    handle_response, handle_response_data, LOGGER,
+};
+use colored::Colorize;
+
+/// Persistent disks, attached at creation with [ServiceDetails::disk] or later with [DiskManager::attach_disk].
+#[derive(Debug)]
+pub struct DiskManager;
+
+pub trait DiskManagerOperations {
+    /// List all disks.
+    fn list_disks(limit: &str) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// List the disks matching the specified name.
+    fn find_disk_by_name(
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// List the disks attached to a service.
+    fn list_disks_for_service(
+        service_name: &str,
+        service_type: ServiceType,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Attach a disk to an existing service.
+    fn attach_disk(
+        service_name: &str,
+        service_type: ServiceType,
+        disk: Disk,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Grow a disk, disks can't shrink.
+    fn resize_disk(
+        name: &str,
+        size_gb: u32,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Delete a disk along with its data.
+    fn delete_disk(name: &str) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// List the snapshots of a disk, taken daily.
+    fn list_disk_snapshots(
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Restore a disk from one of its snapshots, replacing the current data.
+    fn restore_disk_snapshot(
+        name: &str,
+        snapshot_key: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+}
+
+impl DiskManagerOperations for DiskManager {
+    async fn list_disks(limit: &str) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request GET \
+                --url 'https://api.render.com/v1/disks?limit=20' \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!("{}{}{}", state.BASE_URL, "/disks?limit=", limit);
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+        let response = create_get_request!(client, api_url, api_key)?;
+        handle_response_data!(response, "<list_disks>")
+    }
+
+    async fn find_disk_by_name(name: &str) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request GET \
+                --url 'https://api.render.com/v1/disks?name=data' \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("name", name)
+            .finish();
+        let api_url = format!("{}{}{}", state.BASE_URL, "/disks?", query);
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+        let response = create_get_request!(client, api_url, api_key)?;
+        handle_response_data!(response, "<find_disk_by_name>")
+    }
+
+    async fn list_disks_for_service(
+        service_name: &str,
+        service_type: ServiceType,
+    ) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request GET \
+                --url 'https://api.render.com/v1/disks?serviceId=serviceId' \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let service_id = service_id(service_name, service_type).await?;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!("{}{}{}", state.BASE_URL, "/disks?serviceId=", service_id);
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+        let response = create_get_request!(client, api_url, api_key)?;
+        handle_response_data!(response, "<list_disks_for_service>")
+    }
+
+    async fn attach_disk(
+        service_name: &str,
+        service_type: ServiceType,
+        disk: Disk,
+    ) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request POST \
+                --url https://api.render.com/v1/disks \
+                --header 'Accept: application/json' \
+                --header 'Content-Type: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}' \
+                --data '
+                {
+                    "name": "data",
+                    "mountPath": "/var/lib/data",
+                    "sizeGB": 10,
+                    "serviceId": "srv-a1b2c3d4"
+                }'
+
+        *****************************************************************/
+
+        let errors = disk.validate();
+        if !errors.is_empty() {
+            return Err(errors.into());
+        }
+
+        let service_id = service_id(service_name, service_type).await?;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!("{}{}", state.BASE_URL, "/disks");
+        let payload = DiskConf { disk, service_id }.stringify();
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+        LOGGER!("[PAYLOAD] -> ", &payload, LogLevel::WARN);
+
+        let response = create_post_request!(client, api_url, api_key, payload, "<attach_disk>")?;
+        handle_response!(response, "<attach_disk>")
+    }
+
+    async fn resize_disk(name: &str, size_gb: u32) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request PATCH \
+                --url https://api.render.com/v1/disks/diskId \
+                --header 'Accept: application/json' \
+                --header 'Content-Type: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}' \
+                --data '{ "sizeGB": 20 }'
+
+        *****************************************************************/
+
+        let disk = find_disk(name).await?;
+        let current = disk["sizeGB"].as_u64().unwrap_or_default();
+        if (size_gb as u64) < current {
+            return Err(anyhow::anyhow!(
+                "Disk {:?} can't shrink from {}GB to {}GB.",
+                name,
+                current,
+                size_gb
+            ));
+        }
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!(
+            "{}{}{}",
+            state.BASE_URL,
+            "/disks/",
+            disk["id"].as_str().unwrap_or_default()
+        );
+        let payload = DiskUpdate {
+            size_gb: Some(size_gb),
+            ..Default::default()
+        }
+        .stringify();
+
+        // [DEBUG] logs.
+        LOGGER!(
+            "\nProcessing <request> :: <update> -> ",
+            &api_url,
+            LogLevel::WARN
+        );
+        LOGGER!("[PAYLOAD] -> ", &payload, LogLevel::WARN);
+
+        let response = create_patch_request!(client, api_url, api_key, payload, "<resize_disk>")?;
+        handle_response!(response, "<resize_disk>")
+    }
+
+    async fn delete_disk(name: &str) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request DELETE \
+                --url https://api.render.com/v1/disks/diskId \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let disk = find_disk(name).await?;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!(
+            "{}{}{}",
+            state.BASE_URL,
+            "/disks/",
+            disk["id"].as_str().unwrap_or_default()
+        );
+
+        // [DEBUG] logs.
+        LOGGER!(
+            "\nProcessing <request> :: <delete> -> ",
+            &api_url,
+            LogLevel::WARN
+        );
+
+        let response = create_delete_request!(client, api_url, api_key)?;
+        handle_response!(response, "<delete_disk>")
+    }
+
+    async fn list_disk_snapshots(name: &str) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request GET \
+                --url https://api.render.com/v1/disks/diskId/snapshots \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let disk = find_disk(name).await?;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!(
+            "{}{}{}{}",
+            state.BASE_URL,
+            "/disks/",
+            disk["id"].as_str().unwrap_or_default(),
+            "/snapshots"
+        );
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+        let response = create_get_request!(client, api_url, api_key)?;
+        handle_response_data!(response, "<list_disk_snapshots>")
+    }
+
+    async fn restore_disk_snapshot(name: &str, snapshot_key: &str) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request POST \
+                --url https://api.render.com/v1/disks/diskId/snapshots/restore \
+                --header 'Accept: application/json' \
+                --header 'Content-Type: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}' \
+                --data '{ "snapshotKey": "snapshotKey" }'
+
+        *****************************************************************/
+
+        let disk = find_disk(name).await?;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!(
+            "{}{}{}{}",
+            state.BASE_URL,
+            "/disks/",
+            disk["id"].as_str().unwrap_or_default(),
+            "/snapshots/restore"
+        );
+        let payload = json!({ "snapshotKey": snapshot_key }).to_string();
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+        LOGGER!("[PAYLOAD] -> ", &payload, LogLevel::WARN);
+
+        let response =
+            create_post_request!(client, api_url, api_key, payload, "<restore_disk_snapshot>")?;
+        handle_response!(response, "<restore_disk_snapshot>")
+    }
+}
+
+/// The single disk with the specified name.
+async fn find_disk(name: &str) -> Result<Value, Error> {
+    let found = DiskManager::find_disk_by_name(name).await?;
+    let disks: Vec<&Value> = found
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .map(|item| &item["disk"])
+        .filter(|disk| disk["name"] == name)
+        .collect();
+
+    match disks.as_slice() {
+        [disk] => Ok((*disk).clone()),
+        [] => Err(anyhow::anyhow!("Disk {:?} not found.", name)),
+        _ => Err(anyhow::anyhow!(
+            "Found {} disks named {:?}, disk names must be unique to be managed by name.",
+            disks.len(),
+            name
+        )),
+    }
+}
+
+#[cfg(test)]
+mod disk_manager_tests {
+    use super::*;
+    use crate::testing::prelude::*;
+
+    #[tokio::test]
+    async fn test_disk_lifecycle() {
+        shared();
+
+        let minio = WebService::builder()
+            .name("mock_minio")
+            .repo("https://github.com/lexara-prime-ai/SAMPLE_WEB_SERVICE")
+            .runtime(Runtime::Docker)
+            .disk("mock_minio_data", "/data", 10)
+            .build()
+            .unwrap();
+        ServiceManager::create_service(minio).await.unwrap();
+
+        let disks = DiskManager::list_disks_for_service("mock_minio", ServiceType::WebService)
+            .await
+            .unwrap();
+        assert_eq!(disks[0]["disk"]["name"], "mock_minio_data");
+        assert_eq!(disks[0]["disk"]["sizeGB"], 10);
+
+        let resized = DiskManager::resize_disk("mock_minio_data", 20)
+            .await
+            .unwrap();
+        assert_eq!(resized["sizeGB"], 20);
+        assert!(DiskManager::resize_disk("mock_minio_data", 5)
+            .await
+            .is_err());
+
+        DiskManager::delete_disk("mock_minio_data").await.unwrap();
+        let attached = DiskManager::attach_disk(
+            "mock_minio",
+            ServiceType::WebService,
+            Disk::new("mock_minio_data", "/data", 15),
+        )
+        .await
+        .unwrap();
+        assert_eq!(attached["mountPath"], "/data");
+    }
+
+    #[tokio::test]
+    async fn test_find_disk_by_name_encodes_the_name() {
+        shared();
+
+        let found = DiskManager::find_disk_by_name("whoami-data").await.unwrap();
+        assert_eq!(found[0]["disk"]["name"], "whoami-data");
+
+        // The name is a single query value, not extra parameters.
+        let found = DiskManager::find_disk_by_name("whoami-data&limit=1")
+            .await
+            .unwrap();
+        assert_eq!(found.as_array().unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_restore_disk_snapshot() {
+        shared();
+
+        let snapshots = DiskManager::list_disk_snapshots("whoami-data")
+            .await
+            .unwrap();
+        let key = snapshots[0]["snapshotKey"].as_str().unwrap();
+
+        assert!(DiskManager::restore_disk_snapshot("whoami-data", key)
+            .await
+            .is_ok());
+        assert!(DiskManager::restore_disk_snapshot("whoami-data", "missing")
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,237 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+#![allow(non_snake_case)]
+// [JSON] parsing.
+use serde_json::Value;
+
+// Idiomatic [ERROR] handling.
+use anyhow::{Context, Error, Ok, Result};
+
+// HTTP.
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+
+// [render_cdk] modules.
+use crate::resource_management::models::prelude::*;
+use crate::resource_management::services::service_manager::{
+    ServiceManager, ServiceManagerOperations,
+};
+use crate::state_management::state::State;
+
+// [DEBUG] utils.
+use crate::logger::prelude::*;
+use crate::{
+    create_get_request, create_post_request, handle_response, handle_response_data, LOGGER,
+};
+use colored::Colorize;
+
+/// Cron jobs and background workers, created with [ServiceManager::create_service].
+#[derive(Debug)]
+pub struct JobManager;
+
+pub trait JobManagerOperations {
+    /// List all cron jobs.
+    fn list_cron_jobs(
+        limit: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// List all background workers.
+    fn list_background_workers(
+        limit: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Change the schedule and/or command of a cron job.
+    fn update_cron_job(
+        name: &str,
+        schedule: Option<&str>,
+        command: Option<&str>,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Run a cron job now, outside of its schedule.
+    fn trigger_cron_job(
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// List the past runs of a cron job, most recent first.
+    fn list_cron_job_runs(
+        name: &str,
+        limit: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+}
+
+impl JobManagerOperations for JobManager {
+    async fn list_cron_jobs(limit: &str) -> Result<Value, Error> {
+        list_services_of_type(ServiceType::CronJob, limit).await
+    }
+
+    async fn list_background_workers(limit: &str) -> Result<Value, Error> {
+        list_services_of_type(ServiceType::BackgroundWorker, limit).await
+    }
+
+    async fn update_cron_job(
+        name: &str,
+        schedule: Option<&str>,
+        command: Option<&str>,
+    ) -> Result<Value, Error> {
+        let update = ServiceUpdate {
+            service_details: Some(ServiceDetails {
+                schedule: schedule.map(str::to_owned),
+                env_specific_details: command.map(|command| EnvSpecificDetails {
+                    start_command: Some(command.to_owned()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        ServiceManager::update_service(name, ServiceType::CronJob, update).await
+    }
+
+    async fn trigger_cron_job(name: &str) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request POST \
+                --url https://api.render.com/v1/cron-jobs/cronJobId/runs \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let id = cron_job_id(name).await?;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!("{}{}{}{}", state.BASE_URL, "/cron-jobs/", id, "/runs");
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+        let response = create_post_request!(client, api_url, api_key, "", "<trigger_cron_job>")?;
+        handle_response!(response, "<trigger_cron_job>")
+    }
+
+    async fn list_cron_job_runs(name: &str, limit: &str) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request GET \
+                --url 'https://api.render.com/v1/services/serviceId/events?type=cron_job_run_ended&limit=20' \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let id = cron_job_id(name).await?;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!(
+            "{}{}{}{}{}",
+            state.BASE_URL, "/services/", id, "/events?type=cron_job_run_ended&limit=", limit
+        );
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+        let response = create_get_request!(client, api_url, api_key)?;
+        handle_response_data!(response, "<list_cron_job_runs>")
+    }
+}
+
+async fn list_services_of_type(service_type: ServiceType, limit: &str) -> Result<Value, Error> {
+    /*****************************************************
+     *
+        curl --request GET \
+            --url 'https://api.render.com/v1/services?type=cron_job&limit=20' \
+            --header 'Accept: application/json' \
+            --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+    *****************************************************************/
+
+    let state = State::init().await;
+    let client = state.CLIENT;
+    let api_key = state.API_KEY;
+    let api_url = format!(
+        "{}{}{}{}{}",
+        state.BASE_URL, "/services?type=", service_type, "&limit=", limit
+    );
+
+    // [DEBUG] logs.
+    LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+    let response = create_get_request!(client, api_url, api_key)?;
+    handle_response_data!(response, "<list_services_of_type>")
+}
+
+async fn cron_job_id(name: &str) -> Result<String, Error> {
+    let service = ServiceManager::find_service_by_name_and_type(name, ServiceType::CronJob).await?;
+
+    service[0]["service"]["id"]
+        .as_str()
+        .map(str::to_owned)
+        .ok_or_else(|| anyhow::anyhow!("Cron job {:?} not found.", name))
+}
+
+#[cfg(test)]
+mod job_manager_tests {
+    use super::*;
+    use crate::testing::prelude::*;
+    use crate::utils::validation::ValidationErrors;
+
+    #[tokio::test]
+    async fn test_cron_job_lifecycle() {
+        shared();
+
+        let cron_job = CronJob::builder()
+            .name("mock_nightly_report")
+            .repo("https://github.com/lexara-prime-ai/SAMPLE_WEB_SERVICE")
+            .runtime(Runtime::Node)
+            .schedule("0 3 * * *")
+            .start_command("npm run report")
+            .build()
+            .unwrap();
+        ServiceManager::create_service(cron_job).await.unwrap();
+
+        let cron_jobs = JobManager::list_cron_jobs("100").await.unwrap();
+        assert!(cron_jobs
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|item| item["service"]["name"] == "mock_nightly_report"));
+
+        let updated =
+            JobManager::update_cron_job("mock_nightly_report", Some("*/30 * * * *"), None)
+                .await
+                .unwrap();
+        assert_eq!(updated["serviceDetails"]["schedule"], "*/30 * * * *");
+        assert_eq!(
+            updated["serviceDetails"]["envSpecificDetails"]["startCommand"],
+            "npm run report"
+        );
+
+        // Rejected before reaching the API, the schedule is left as is.
+        let invalid = JobManager::update_cron_job("mock_nightly_report", Some("every night"), None)
+            .await
+            .unwrap_err()
+            .downcast::<ValidationErrors>()
+            .unwrap();
+        assert_eq!(invalid.0[0].field, "serviceDetails.schedule");
+        let cron_jobs = JobManager::list_cron_jobs("100").await.unwrap();
+        assert!(cron_jobs.as_array().unwrap().iter().any(|item| {
+            item["service"]["name"] == "mock_nightly_report"
+                && item["service"]["serviceDetails"]["schedule"] == "*/30 * * * *"
+        }));
+
+        let run = JobManager::trigger_cron_job("mock_nightly_report")
+            .await
+            .unwrap();
+        let runs = JobManager::list_cron_job_runs("mock_nightly_report", "10")
+            .await
+            .unwrap();
+        assert_eq!(runs[0]["event"]["details"]["cronJobRunId"], run["id"]);
+
+        let missing = JobManager::trigger_cron_job("mock_missing_cron_job").await;
+        assert!(missing.is_err());
+    }
+}
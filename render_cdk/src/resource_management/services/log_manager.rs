@@ -0,0 +1,455 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+#![allow(non_snake_case)]
+// [JSON] parsing.
+use serde_json::Value;
+
+// Idiomatic [ERROR] handling.
+use anyhow::{Context, Error, Ok, Result};
+
+// HTTP.
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+
+// [render_cdk] modules.
+use crate::authentication::owner::Info;
+use crate::resource_management::models::prelude::*;
+use crate::resource_management::services::postgres_manager::postgres_id;
+use crate::resource_management::services::redis_manager::redis_id;
+use crate::resource_management::services::service_manager::service_id;
+use crate::state_management::state::State;
+
+// [DEBUG] utils.
+use crate::logger::prelude::*;
+use crate::{create_get_request, handle_response, LOGGER};
+use colored::Colorize;
+
+use chrono::{DateTime, Utc};
+use futures::stream::{self, BoxStream, StreamExt};
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+/// Delay between two polls of [LogManager::tail_logs], unless more entries are pending.
+const TAIL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Entries requested per poll of [LogManager::tail_logs].
+const TAIL_LIMIT: &str = "100";
+
+/// Logs of services, Postgres and Redis instances.
+#[derive(Debug)]
+pub struct LogManager;
+
+pub trait LogManagerOperations {
+    /// The most recent log entries of a resource matching the filters, newest first.
+    fn list_logs(
+        name: &str,
+        kind: ResourceKind,
+        filters: LogFilters,
+        limit: &str,
+    ) -> impl std::future::Future<Output = Result<LogPage, Error>> + Send;
+
+    /// Follow the log entries of a resource matching the filters, oldest first from
+    /// `filters.start_time` (or now), until `filters.end_time` if set.
+    ///
+    /// Failed polls are yielded as errors without ending the stream.
+    fn tail_logs(
+        name: &str,
+        kind: ResourceKind,
+        filters: LogFilters,
+    ) -> impl std::future::Future<Output = Result<BoxStream<'static, Result<LogEntry, Error>>, Error>>
+           + Send;
+}
+
+impl LogManagerOperations for LogManager {
+    async fn list_logs(
+        name: &str,
+        kind: ResourceKind,
+        filters: LogFilters,
+        limit: &str,
+    ) -> Result<LogPage, Error> {
+        let (owner_id, id, filters) = resolve(name, &kind, filters).await?;
+
+        fetch_logs(&owner_id, &id, &filters, limit, "backward", true).await
+    }
+
+    async fn tail_logs(
+        name: &str,
+        kind: ResourceKind,
+        filters: LogFilters,
+    ) -> Result<BoxStream<'static, Result<LogEntry, Error>>, Error> {
+        let (owner_id, id, filters) = resolve(name, &kind, filters).await?;
+
+        let tail = Tail {
+            cursor: filters.start_time.unwrap_or_else(Utc::now),
+            owner_id,
+            id,
+            filters,
+            seen: HashSet::new(),
+            pending: VecDeque::new(),
+            wait: false,
+            done: false,
+        };
+
+        Ok(stream::unfold(tail, next_entry).boxed())
+    }
+}
+
+/// Progress of a [LogManager::tail_logs] stream.
+struct Tail {
+    owner_id: String,
+    id: String,
+    filters: LogFilters,
+    /// Timestamp of the latest entry received, the start of the next poll.
+    cursor: DateTime<Utc>,
+    /// Ids of the entries at [Tail::cursor], which the next poll returns again.
+    seen: HashSet<String>,
+    pending: VecDeque<LogEntry>,
+    /// Whether to wait [TAIL_INTERVAL] before the next poll.
+    wait: bool,
+    done: bool,
+}
+
+async fn next_entry(mut tail: Tail) -> Option<(Result<LogEntry, Error>, Tail)> {
+    loop {
+        if let Some(entry) = tail.pending.pop_front() {
+            return Some((Result::Ok(entry), tail));
+        }
+        if tail.done {
+            return None;
+        }
+        if tail.wait {
+            tokio::time::sleep(TAIL_INTERVAL).await;
+        }
+
+        let mut filters = tail.filters.clone();
+        filters.start_time = Some(tail.cursor);
+        let page = match fetch_logs(
+            &tail.owner_id,
+            &tail.id,
+            &filters,
+            TAIL_LIMIT,
+            "forward",
+            false,
+        )
+        .await
+        {
+            Result::Ok(page) => page,
+            Err(error) => {
+                tail.wait = true;
+                return Some((Err(error), tail));
+            }
+        };
+
+        for entry in page.logs {
+            if tail.seen.contains(&entry.id) {
+                continue;
+            }
+            if entry.timestamp > tail.cursor {
+                tail.cursor = entry.timestamp;
+                tail.seen.clear();
+            }
+            tail.seen.insert(entry.id.clone());
+            tail.pending.push_back(entry);
+        }
+
+        // A full page of entries already seen, all at the cursor, would be returned forever.
+        // Move past it, the remaining entries with that exact timestamp are skipped.
+        if tail.pending.is_empty() && page.hasMore {
+            tail.cursor = page
+                .nextStartTime
+                .filter(|next| *next > tail.cursor)
+                .unwrap_or(tail.cursor + chrono::Duration::nanoseconds(1));
+            tail.seen.clear();
+        }
+
+        tail.wait = !page.hasMore;
+        tail.done = !page.hasMore
+            && tail
+                .filters
+                .end_time
+                .is_some_and(|end_time| end_time <= Utc::now());
+    }
+}
+
+/// The owner and id of the resource, with `deploy_id` resolved to a time range.
+async fn resolve(
+    name: &str,
+    kind: &ResourceKind,
+    mut filters: LogFilters,
+) -> Result<(String, String, LogFilters), Error> {
+    let id = match kind {
+        ResourceKind::Postgres => postgres_id(name).await?,
+        ResourceKind::Redis => redis_id(name).await?,
+        ResourceKind::Service(service_type) => service_id(name, service_type.clone()).await?,
+    };
+
+    if let Some(deploy_id) = &filters.deploy_id {
+        if !matches!(kind, ResourceKind::Service(_)) {
+            return Err(anyhow::anyhow!(
+                "Deploy filters only apply to services, not {}.",
+                kind
+            ));
+        }
+
+        let deploy = retrieve_deploy(&id, deploy_id).await?;
+        let time = |field: &str| {
+            deploy[field]
+                .as_str()
+                .and_then(|time| DateTime::parse_from_rfc3339(time).ok())
+                .map(|time| time.with_timezone(&Utc))
+        };
+        filters.start_time = filters.start_time.or_else(|| time("createdAt"));
+        filters.end_time = filters.end_time.or_else(|| time("finishedAt"));
+    }
+
+    Ok((Info::whoami().await?.id, id, filters))
+}
+
+async fn retrieve_deploy(service_id: &str, deploy_id: &str) -> Result<Value, Error> {
+    /*****************************************************
+     *
+        curl --request GET \
+            --url https://api.render.com/v1/services/serviceId/deploys/deployId \
+            --header 'Accept: application/json' \
+            --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+    *****************************************************************/
+
+    let state = State::init().await;
+    let client = state.CLIENT;
+    let api_key = state.API_KEY;
+    let api_url = format!(
+        "{}{}{}{}{}",
+        state.BASE_URL, "/services/", service_id, "/deploys/", deploy_id
+    );
+
+    // [DEBUG] logs.
+    LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+    let response = create_get_request!(client, api_url, api_key)?;
+    handle_response!(response, "<retrieve_deploy>")
+}
+
+async fn fetch_logs(
+    owner_id: &str,
+    id: &str,
+    filters: &LogFilters,
+    limit: &str,
+    direction: &str,
+    verbose: bool,
+) -> Result<LogPage, Error> {
+    /*****************************************************
+     *
+        curl --request GET \
+            --url 'https://api.render.com/v1/logs?ownerId=ownerId&resource=srv-a1b2c3d4&level=error&limit=20&direction=backward' \
+            --header 'Accept: application/json' \
+            --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+    *****************************************************************/
+
+    let state = State::init().await;
+    let client = state.CLIENT;
+    let api_key = state.API_KEY;
+    let mut api_url = format!(
+        "{}/logs?ownerId={}&resource={}&limit={}&direction={}",
+        state.BASE_URL, owner_id, id, limit, direction
+    );
+    let query = filters.query();
+    if !query.is_empty() {
+        api_url = format!("{}&{}", api_url, query);
+    }
+
+    // [DEBUG] logs, skipped while tailing since every poll would be logged.
+    if verbose {
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+    }
+
+    let response = create_get_request!(client, api_url, api_key)?;
+
+    // Unlike [handle_response!], the response isn't logged since the entries are returned as is.
+    let status = response.status();
+    let result = response.text().await.context("Error parsing response.")?;
+    if status.is_success() {
+        let page: LogPage = serde_json::from_str(&result)?;
+        Ok(page)
+    } else {
+        let data: Value = serde_json::from_str(&result)?;
+        let message = data["message"]
+            .as_str()
+            .unwrap_or("An error occurred :: Process -> <list_logs>");
+
+        LOGGER!(
+            "<response status> -> ",
+            format!("{:#?}", message),
+            LogLevel::CRITICAL
+        );
+
+        Err(anyhow::anyhow!("<Error>: {:#?}", data))
+    }
+}
+
+#[cfg(test)]
+mod log_manager_tests {
+    use super::*;
+    use crate::resource_management::models::enums::ServiceType;
+    use crate::testing::prelude::*;
+    use serde_json::json;
+
+    fn web_service() -> ResourceKind {
+        ResourceKind::Service(ServiceType::WebService)
+    }
+
+    fn since(time: &str) -> Option<DateTime<Utc>> {
+        Some(time.parse().unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_list_logs_with_filters() {
+        shared();
+
+        let page = LogManager::list_logs("whoami", web_service(), LogFilters::default(), "20")
+            .await
+            .unwrap();
+        let messages: Vec<&str> = page.logs.iter().map(|log| log.message.as_str()).collect();
+        assert_eq!(messages.len(), 4);
+        assert_eq!(messages[0], "Unhandled rejection: connection refused");
+
+        let errors = LogFilters {
+            levels: vec!["error".to_owned()],
+            ..Default::default()
+        };
+        let page = LogManager::list_logs("whoami", web_service(), errors, "20")
+            .await
+            .unwrap();
+        assert_eq!(page.logs.len(), 1);
+        assert_eq!(page.logs[0].label("level"), Some("error"));
+
+        let window = LogFilters {
+            start_time: since("2024-01-01T00:01:00Z"),
+            end_time: since("2024-01-01T00:02:00Z"),
+            text: vec!["GET".to_owned(), "Listening".to_owned()],
+            ..Default::default()
+        };
+        let page = LogManager::list_logs("whoami", web_service(), window, "1")
+            .await
+            .unwrap();
+        assert_eq!(page.logs[0].message, "GET / 200");
+        assert!(page.hasMore);
+        assert_eq!(page.nextEndTime, since("2024-01-01T00:02:00Z"));
+
+        let postgres = LogManager::list_logs(
+            "fluentcomet",
+            ResourceKind::Postgres,
+            LogFilters::default(),
+            "20",
+        )
+        .await
+        .unwrap();
+        assert_eq!(postgres.logs.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_logs_of_deploy() {
+        let server = shared();
+        server
+            .state()
+            .deploys
+            .entry("srv-mockwhoami000000000".to_owned())
+            .or_default()
+            .push(json!({
+                "id": "dep-mocklogs0000000000",
+                "status": "live",
+                "createdAt": "2024-01-01T00:00:00Z",
+                "finishedAt": "2024-01-01T00:01:30Z"
+            }));
+
+        let filters = LogFilters {
+            deploy_id: Some("dep-mocklogs0000000000".to_owned()),
+            ..Default::default()
+        };
+        let page = LogManager::list_logs("whoami", web_service(), filters.clone(), "20")
+            .await
+            .unwrap();
+        assert_eq!(page.logs.len(), 2);
+
+        let redis = LogManager::list_logs("fluentcomet", ResourceKind::Redis, filters, "20").await;
+        assert!(redis.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_tail_logs() {
+        let server = shared();
+        let image = "srv-mockimage0000000000";
+        server.state().logs.push(log_entry(
+            image,
+            "2024-01-01T00:00:00Z",
+            "info",
+            "app",
+            "mock_initial_entry",
+        ));
+
+        let filters = LogFilters {
+            start_time: since("2024-01-01T00:00:00Z"),
+            types: vec!["app".to_owned()],
+            ..Default::default()
+        };
+        let mut logs = LogManager::tail_logs("whoami-image", web_service(), filters)
+            .await
+            .unwrap();
+        assert_eq!(
+            logs.next().await.unwrap().unwrap().message,
+            "mock_initial_entry"
+        );
+
+        server.state().logs.push(log_entry(
+            image,
+            &Utc::now().to_rfc3339(),
+            "info",
+            "app",
+            "mock_tailed_entry",
+        ));
+        let tailed = tokio::time::timeout(Duration::from_secs(10), logs.next())
+            .await
+            .unwrap();
+        assert_eq!(tailed.unwrap().unwrap().message, "mock_tailed_entry");
+    }
+
+    #[tokio::test]
+    async fn test_tail_logs_moves_past_a_full_page() {
+        let server = shared();
+        let image = "srv-mockimage0000000000";
+        {
+            let mut state = server.state();
+            // More entries at a single timestamp than a poll returns.
+            for index in 0..=100 {
+                let mut entry =
+                    log_entry(image, "2024-02-01T00:00:00Z", "info", "burst", "mock_burst");
+                entry["id"] = json!(format!("log-mock-burst-{}", index));
+                state.logs.push(entry);
+            }
+            state.logs.push(log_entry(
+                image,
+                "2024-02-01T00:00:01Z",
+                "info",
+                "burst",
+                "mock_after_burst",
+            ));
+        }
+
+        let filters = LogFilters {
+            start_time: since("2024-02-01T00:00:00Z"),
+            types: vec!["burst".to_owned()],
+            ..Default::default()
+        };
+        let logs = LogManager::tail_logs("whoami-image", web_service(), filters)
+            .await
+            .unwrap();
+        let mut after = logs
+            .filter_map(|log| async move { log.ok() })
+            .skip_while(|log| futures::future::ready(log.message == "mock_burst"))
+            .boxed();
+        let after = tokio::time::timeout(Duration::from_secs(10), after.next())
+            .await
+            .unwrap();
+        assert_eq!(after.unwrap().message, "mock_after_burst");
+    }
+}
@@ -1 +1,7 @@
+pub mod disk_manager;
+pub mod job_manager;
+pub mod log_manager;
+pub mod postgres_manager;
+pub mod redis_manager;
+pub mod registry_manager;
 pub mod service_manager;
@@ -0,0 +1,882 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+#![allow(non_snake_case)]
+// [JSON] parsing.
+use serde_json::Value;
+
+// Idiomatic [ERROR] handling.
+use anyhow::{Context, Error, Ok, Result};
+
+// HTTP.
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+
+// [render_cdk] modules.
+use crate::authentication::owner::Info;
+use crate::policy_management::prelude::{PolicyManager, Resource};
+use crate::resource_management::models::postgres::{
+    PostgresConf, PostgresConnectionInfo, PostgresInstance, PostgresRecovery, PostgresSpec,
+    PostgresUpdate, ReadReplica,
+};
+use crate::resource_management::services::service_manager::{
+    ServiceManager, ServiceManagerOperations,
+};
+use crate::state_management::state::State;
+use crate::utils::config::Conf;
+use crate::utils::naming::check_name_collisions;
+use crate::utils::random::GENERATE_UNIQUE_NAME;
+use crate::utils::validation::Validate;
+
+// [DEBUG] utils.
+use crate::logger::prelude::*;
+use crate::utils::stringify::Stringify;
+use crate::{
+    create_get_request, create_patch_request, create_post_request, handle_response,
+    handle_response_data, LOGGER,
+};
+use colored::Colorize;
+
+use chrono::{DateTime, Utc};
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+
+/// Postgres instances after creation, see [ServiceManager::list_postgres_instances] for lookups.
+#[derive(Debug)]
+pub struct PostgresManager;
+
+pub trait PostgresManagerOperations {
+    /// Create an instance, validated and checked against the policies like a `[database]` section.
+    fn create_postgres(
+        spec: PostgresSpec,
+    ) -> impl std::future::Future<Output = Result<PostgresInstance, Error>> + Send;
+
+    /// Change the name, plan, storage, high availability and/or IP allow list of an instance.
+    fn update_postgres(
+        name: &str,
+        update: PostgresUpdate,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Suspend an instance, its data is kept.
+    fn suspend_postgres(
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Resume a suspended instance.
+    fn resume_postgres(
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Restart an instance, dropping open connections.
+    fn restart_postgres(
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// The password, connection strings and `psql` command of an instance.
+    fn get_postgres_connection_info(
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<PostgresConnectionInfo, Error>> + Send;
+
+    /// List the read replicas of an instance.
+    fn list_read_replicas(
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<Vec<ReadReplica>, Error>> + Send;
+
+    /// Add a read replica to an instance.
+    fn add_read_replica(
+        name: &str,
+        replica_name: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Delete a read replica of an instance.
+    fn remove_read_replica(
+        name: &str,
+        replica_name: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// List the exports (logical backups) of an instance, most recent first.
+    fn list_postgres_exports(
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Start exporting an instance, listed by [Self::list_postgres_exports] once done.
+    fn export_postgres(
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Download an export, the most recent one unless `export_id` is set, to `path`.
+    ///
+    /// `on_progress` receives the bytes written so far and the total size when known.
+    /// Returns the size of the export.
+    fn download_postgres_export(
+        name: &str,
+        export_id: Option<&str>,
+        path: &Path,
+        on_progress: impl FnMut(u64, Option<u64>) + Send,
+    ) -> impl std::future::Future<Output = Result<u64, Error>> + Send;
+
+    /// Whether point-in-time recovery is available and the earliest time it can restore.
+    fn get_postgres_recovery_info(
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Restore an instance as it was at `restore_time` into a new instance, the original is left as is.
+    fn recover_postgres(
+        name: &str,
+        restore_time: DateTime<Utc>,
+        restore_name: Option<&str>,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+}
+
+impl PostgresManagerOperations for PostgresManager {
+    async fn create_postgres(mut spec: PostgresSpec) -> Result<PostgresInstance, Error> {
+        if spec.ownerId.is_none() {
+            spec.ownerId = Some(Info::whoami().await?.id);
+        }
+        if spec.name.as_deref().is_none_or(str::is_empty) {
+            spec.name = Some(GENERATE_UNIQUE_NAME());
+        }
+
+        let config = Conf {
+            database: Some(spec.clone().into()),
+            ..Default::default()
+        };
+        let errors = config.validate();
+        if !errors.is_empty() {
+            return Err(errors.into());
+        }
+        PolicyManager::check(&Resource::Configuration(&config))?;
+        check_name_collisions(&config).await?;
+
+        post_postgres(spec).await
+    }
+
+    async fn update_postgres(name: &str, update: PostgresUpdate) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request PATCH \
+                --url https://api.render.com/v1/postgres/postgresId \
+                --header 'Accept: application/json' \
+                --header 'Content-Type: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}' \
+                --data '
+                {
+                    "plan": "pro_8gb",
+                    "enableHighAvailability": true
+                }'
+
+        *****************************************************************/
+
+        let errors = update.validate();
+        if !errors.is_empty() {
+            return Err(errors.into());
+        }
+
+        // Checked against the state the instance ends up in.
+        let current = find_postgres(name).await?;
+        PolicyManager::check(&Resource::PostgresUpdate(&with_current_values(
+            &update, &current,
+        )))?;
+        let id = current["id"].as_str().unwrap_or_default().to_owned();
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!("{}{}{}", state.BASE_URL, "/postgres/", id);
+        let payload = update.stringify();
+
+        // [DEBUG] logs.
+        LOGGER!(
+            "\nProcessing <request> :: <update> -> ",
+            &api_url,
+            LogLevel::WARN
+        );
+        LOGGER!("[PAYLOAD] -> ", &payload, LogLevel::WARN);
+
+        let response =
+            create_patch_request!(client, api_url, api_key, payload, "<update_postgres>")?;
+        handle_response!(response, "<update_postgres>")
+    }
+
+    async fn suspend_postgres(name: &str) -> Result<Value, Error> {
+        postgres_action(name, "suspend").await
+    }
+
+    async fn resume_postgres(name: &str) -> Result<Value, Error> {
+        postgres_action(name, "resume").await
+    }
+
+    async fn restart_postgres(name: &str) -> Result<Value, Error> {
+        postgres_action(name, "restart").await
+    }
+
+    async fn get_postgres_connection_info(name: &str) -> Result<PostgresConnectionInfo, Error> {
+        /*****************************************************
+         *
+            curl --request GET \
+                --url https://api.render.com/v1/postgres/postgresId/connection-info \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let id = postgres_id(name).await?;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!(
+            "{}{}{}{}",
+            state.BASE_URL, "/postgres/", id, "/connection-info"
+        );
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+        let response = create_get_request!(client, api_url, api_key)?;
+
+        // Unlike [handle_response!], the response isn't logged since it holds the password.
+        if response.status().is_success() {
+            let result = response.text().await.context("Error parsing response.")?;
+            let info: PostgresConnectionInfo = serde_json::from_str(&result)?;
+
+            LOGGER!("<response> -> ", format!("{:#?}", info), LogLevel::SUCCESS);
+
+            Ok(info)
+        } else {
+            let result = response.text().await.context("Error parsing response.")?;
+            let data: Value = serde_json::from_str(&result)?;
+            let message = data["message"]
+                .as_str()
+                .unwrap_or("An error occurred :: Process -> <get_postgres_connection_info>");
+
+            LOGGER!(
+                "<response status> -> ",
+                format!("{:#?}", message),
+                LogLevel::CRITICAL
+            );
+
+            Err(anyhow::anyhow!("<Error>: {:#?}", data))
+        }
+    }
+
+    async fn list_read_replicas(name: &str) -> Result<Vec<ReadReplica>, Error> {
+        let postgres = find_postgres(name).await?;
+
+        Ok(serde_json::from_value(postgres["readReplicas"].clone()).unwrap_or_default())
+    }
+
+    async fn add_read_replica(name: &str, replica_name: &str) -> Result<Value, Error> {
+        let mut replicas = PostgresManager::list_read_replicas(name).await?;
+        if replicas.iter().any(|replica| replica.name == replica_name) {
+            return Err(anyhow::anyhow!(
+                "Postgres instance {:?} already has a read replica named {:?}.",
+                name,
+                replica_name
+            ));
+        }
+
+        replicas.push(ReadReplica {
+            name: replica_name.to_owned(),
+        });
+        update_read_replicas(name, replicas).await
+    }
+
+    async fn remove_read_replica(name: &str, replica_name: &str) -> Result<Value, Error> {
+        let replicas = PostgresManager::list_read_replicas(name).await?;
+        let count = replicas.len();
+        let replicas: Vec<ReadReplica> = replicas
+            .into_iter()
+            .filter(|replica| replica.name != replica_name)
+            .collect();
+
+        if replicas.len() == count {
+            return Err(anyhow::anyhow!(
+                "Read replica {:?} of postgres instance {:?} not found.",
+                replica_name,
+                name
+            ));
+        }
+        update_read_replicas(name, replicas).await
+    }
+
+    async fn list_postgres_exports(name: &str) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request GET \
+                --url https://api.render.com/v1/postgres/postgresId/export \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let id = postgres_id(name).await?;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!("{}{}{}{}", state.BASE_URL, "/postgres/", id, "/export");
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+        let response = create_get_request!(client, api_url, api_key)?;
+        handle_response_data!(response, "<list_postgres_exports>")
+    }
+
+    async fn export_postgres(name: &str) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request POST \
+                --url https://api.render.com/v1/postgres/postgresId/export \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let id = postgres_id(name).await?;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!("{}{}{}{}", state.BASE_URL, "/postgres/", id, "/export");
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+        let response = create_post_request!(client, api_url, api_key, "", "<export_postgres>")?;
+        handle_response!(response, "<export_postgres>")
+    }
+
+    async fn download_postgres_export(
+        name: &str,
+        export_id: Option<&str>,
+        path: &Path,
+        mut on_progress: impl FnMut(u64, Option<u64>) + Send,
+    ) -> Result<u64, Error> {
+        let exports = PostgresManager::list_postgres_exports(name).await?;
+        let export = exports
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .map(|item| item.get("export").unwrap_or(item))
+            .find(|export| export_id.is_none_or(|id| export["id"] == id))
+            .ok_or_else(|| anyhow::anyhow!("No export of postgres instance {:?} found.", name))?;
+        let url = export["url"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("Export {} has no download url yet.", export["id"]))?;
+
+        // The url is pre-signed, so the API key isn't sent along.
+        let client = State::init().await.CLIENT;
+        let mut response = client.get(url).send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!(
+                "Unable to download export {} :: <status> -> {}",
+                export["id"],
+                response.status()
+            ));
+        }
+
+        // Written next to the destination, then renamed so an interrupted download leaves no partial file behind.
+        let mut partial = path.as_os_str().to_owned();
+        partial.push(".part");
+        let partial = PathBuf::from(partial);
+        let written = async {
+            let mut file = tokio::fs::File::create(&partial)
+                .await
+                .with_context(|| format!("Unable to create {}.", partial.display()))?;
+
+            let total = response.content_length();
+            let mut downloaded = 0;
+            while let Some(chunk) = response.chunk().await? {
+                file.write_all(&chunk).await?;
+                downloaded += chunk.len() as u64;
+                on_progress(downloaded, total);
+            }
+            file.flush().await?;
+            drop(file);
+
+            tokio::fs::rename(&partial, path)
+                .await
+                .with_context(|| format!("Unable to write {}.", path.display()))?;
+            Ok(downloaded)
+        }
+        .await;
+
+        let downloaded = match written {
+            Result::Ok(downloaded) => downloaded,
+            Err(error) => {
+                // The download error is the one reported.
+                let _ = tokio::fs::remove_file(&partial).await;
+                return Err(error);
+            }
+        };
+
+        LOGGER!(
+            "<response> -> ",
+            format!("Downloaded {} bytes to {}", downloaded, path.display()),
+            LogLevel::SUCCESS
+        );
+
+        Ok(downloaded)
+    }
+
+    async fn get_postgres_recovery_info(name: &str) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request GET \
+                --url https://api.render.com/v1/postgres/postgresId/recovery \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let id = postgres_id(name).await?;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!("{}{}{}{}", state.BASE_URL, "/postgres/", id, "/recovery");
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+        let response = create_get_request!(client, api_url, api_key)?;
+        handle_response!(response, "<get_postgres_recovery_info>")
+    }
+
+    async fn recover_postgres(
+        name: &str,
+        restore_time: DateTime<Utc>,
+        restore_name: Option<&str>,
+    ) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request POST \
+                --url https://api.render.com/v1/postgres/postgresId/recovery \
+                --header 'Accept: application/json' \
+                --header 'Content-Type: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}' \
+                --data '
+                {
+                    "restoreTime": "2024-01-01T00:00:00Z",
+                    "restoreName": "mydb-restored"
+                }'
+
+        *****************************************************************/
+
+        if restore_time > Utc::now() {
+            return Err(anyhow::anyhow!(
+                "Unable to recover postgres instance {:?} to {}, which is in the future.",
+                name,
+                restore_time
+            ));
+        }
+
+        let id = postgres_id(name).await?;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!("{}{}{}{}", state.BASE_URL, "/postgres/", id, "/recovery");
+        let payload = PostgresRecovery {
+            restoreTime: restore_time.to_rfc3339(),
+            restoreName: restore_name.map(str::to_owned),
+        }
+        .stringify();
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+        LOGGER!("[PAYLOAD] -> ", &payload, LogLevel::WARN);
+
+        let response =
+            create_post_request!(client, api_url, api_key, payload, "<recover_postgres>")?;
+        handle_response!(response, "<recover_postgres>")
+    }
+}
+
+/// Creates an instance from a spec that has already been checked, see [PostgresManager::create_postgres].
+pub(crate) async fn post_postgres(spec: PostgresSpec) -> Result<PostgresInstance, Error> {
+    /*****************************************************
+     *
+        curl --request POST \
+            --url https://api.render.com/v1/postgres \
+            --header 'Accept: application/json' \
+            --header 'Content-Type: application/json' \
+            --header 'Authorization: Bearer {{render_api_token_goes_here}}' \
+            --data '
+            {
+                "name": "mydb",
+                "plan": "basic_256mb",
+                "version": "16",
+                "ownerId": "usr-a1b2c3d4"
+            }'
+
+    *****************************************************************/
+
+    let state = State::init().await;
+    let client = state.CLIENT;
+    let api_key = state.API_KEY;
+    let api_url = format!("{}{}", state.BASE_URL, "/postgres");
+    let payload = PostgresConf {
+        databaseName: spec.databaseName,
+        databaseUser: spec.databaseUser,
+        enableHighAvailability: spec.enableHighAvailability,
+        plan: spec.plan,
+        version: spec.version,
+        name: spec.name,
+        region: spec.region,
+        ownerId: spec.ownerId.unwrap_or_default(),
+        ipAllowList: Some(spec.ipAllowList),
+    }
+    .stringify();
+
+    // [DEBUG] logs.
+    LOGGER!(
+        "\n<request> :: Creating request -> ",
+        &api_url,
+        LogLevel::WARN
+    );
+    LOGGER!("[PAYLOAD] :: -> ", &payload, LogLevel::WARN);
+
+    let response = create_post_request!(client, api_url, api_key, payload, "<postgres>")?;
+    let data = handle_response!(response, "<create_postgres>")?;
+    let postgres: PostgresInstance = serde_json::from_value(data)?;
+
+    LOGGER!(
+        "[POSTGRES] :: Deployment successful. -> ",
+        format!("{:#?}", postgres.dashboardUrl),
+        LogLevel::SUCCESS
+    );
+
+    Ok(postgres)
+}
+
+/// Replicas are managed through the primary's list of replicas.
+async fn update_read_replicas(name: &str, replicas: Vec<ReadReplica>) -> Result<Value, Error> {
+    let update = PostgresUpdate {
+        readReplicas: Some(replicas),
+        ..Default::default()
+    };
+
+    PostgresManager::update_postgres(name, update).await
+}
+
+/// Suspends, resumes or restarts an instance.
+async fn postgres_action(name: &str, action: &str) -> Result<Value, Error> {
+    /*****************************************************
+     *
+        curl --request POST \
+            --url https://api.render.com/v1/postgres/postgresId/suspend \
+            --header 'Accept: application/json' \
+            --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+    *****************************************************************/
+
+    let id = postgres_id(name).await?;
+
+    let state = State::init().await;
+    let client = state.CLIENT;
+    let api_key = state.API_KEY;
+    let api_url = format!("{}{}{}{}{}", state.BASE_URL, "/postgres/", id, "/", action);
+
+    // [DEBUG] logs.
+    LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+    let response = create_post_request!(client, api_url, api_key, "", "<postgres_action>")?;
+    handle_response!(response, "<postgres_action>")
+}
+
+/// The primary instance with the specified name, replicas are looked up through it.
+async fn find_postgres(name: &str) -> Result<Value, Error> {
+    let found = ServiceManager::find_postgres_instance_by_name(name, false, "100").await?;
+
+    found
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .map(|item| &item["postgres"])
+        .find(|postgres| postgres["name"] == name)
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("Postgres instance {:?} not found.", name))
+}
+
+/// The update with the plan and high availability it leaves unchanged filled in from `current`.
+fn with_current_values(update: &PostgresUpdate, current: &Value) -> PostgresUpdate {
+    PostgresUpdate {
+        plan: update
+            .plan
+            .clone()
+            .or_else(|| current["plan"].as_str().map(str::to_owned)),
+        enableHighAvailability: update
+            .enableHighAvailability
+            .or_else(|| current["highAvailabilityEnabled"].as_bool()),
+        ..update.clone()
+    }
+}
+
+pub(crate) async fn postgres_id(name: &str) -> Result<String, Error> {
+    let postgres = find_postgres(name).await?;
+
+    Ok(postgres["id"].as_str().unwrap_or_default().to_owned())
+}
+
+#[cfg(test)]
+mod postgres_manager_tests {
+    use super::*;
+    use crate::policy_management::prelude::Policy;
+    use crate::resource_management::models::enums::Region;
+    use crate::resource_management::models::storage::PostgresCidrAllowList;
+    use crate::testing::prelude::*;
+
+    #[tokio::test]
+    async fn test_create_postgres() {
+        shared();
+
+        let created = PostgresManager::create_postgres(PostgresSpec {
+            region: Some(Region::Frankfurt),
+            ..PostgresSpec::new("mock_harness_db", "basic_256mb", "16")
+        })
+        .await
+        .unwrap();
+        assert!(created.id.starts_with("dpg-"));
+        assert_eq!(created.name, "mock_harness_db");
+        assert_eq!(created.region, Region::Frankfurt);
+        assert!(created.dashboardUrl.ends_with(&created.id));
+
+        // The name is already taken.
+        let duplicate = PostgresManager::create_postgres(PostgresSpec::new(
+            "mock_harness_db",
+            "basic_256mb",
+            "16",
+        ))
+        .await;
+        assert!(duplicate.is_err());
+
+        // Rejected before reaching the API.
+        let invalid =
+            PostgresManager::create_postgres(PostgresSpec::new("mock_invalid_db", "huge", "16"))
+                .await;
+        assert!(invalid.is_err());
+        assert!(find_postgres("mock_invalid_db").await.is_err());
+    }
+
+    #[test]
+    fn test_update_policy_uses_current_values() {
+        let violations = |update: PostgresUpdate, current: Value| -> Vec<String> {
+            let update = with_current_values(&update, &current);
+            Policy::default()
+                .evaluate(&Resource::PostgresUpdate(&update))
+                .violations
+                .into_iter()
+                .map(|v| v.rule)
+                .collect()
+        };
+
+        // Turning high availability off on a production plan.
+        let disabled = PostgresUpdate {
+            enableHighAvailability: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(
+            violations(
+                disabled,
+                serde_json::json!({ "plan": "pro_8gb", "highAvailabilityEnabled": true })
+            ),
+            ["high-availability-on-production-plans"]
+        );
+
+        // Moving to a production plan without high availability.
+        let upgraded = PostgresUpdate {
+            plan: Some("pro_8gb".to_owned()),
+            ..Default::default()
+        };
+        let current = serde_json::json!({ "plan": "basic_1gb", "highAvailabilityEnabled": false });
+        assert_eq!(
+            violations(upgraded.clone(), current.clone()),
+            ["high-availability-on-production-plans"]
+        );
+        assert!(violations(
+            PostgresUpdate {
+                enableHighAvailability: Some(true),
+                ..upgraded
+            },
+            current
+        )
+        .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_and_suspend_postgres() {
+        let server = shared();
+        server.state().postgres.push(postgres(
+            "dpg-mocklifecycle000000",
+            "mock_lifecycle",
+            "not_suspended",
+        ));
+
+        let updated = PostgresManager::update_postgres(
+            "mock_lifecycle",
+            PostgresUpdate {
+                plan: Some("pro_8gb".to_owned()),
+                enableHighAvailability: Some(true),
+                ipAllowList: Some(vec![PostgresCidrAllowList {
+                    cidrBlock: "10.0.0.0/8".to_owned(),
+                    description: "office".to_owned(),
+                }]),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated["plan"], "pro_8gb");
+        assert_eq!(updated["highAvailabilityEnabled"], true);
+        assert_eq!(updated["ipAllowList"][0]["cidrBlock"], "10.0.0.0/8");
+
+        // Rejected before reaching the API.
+        let invalid = PostgresManager::update_postgres(
+            "mock_lifecycle",
+            PostgresUpdate {
+                plan: Some("huge".to_owned()),
+                ..Default::default()
+            },
+        )
+        .await;
+        assert!(invalid.is_err());
+
+        // Blocked by the policy, the allow list is left as is.
+        let blocked = PostgresManager::update_postgres(
+            "mock_lifecycle",
+            PostgresUpdate {
+                ipAllowList: Some(vec![PostgresCidrAllowList {
+                    cidrBlock: "0.0.0.0/0".to_owned(),
+                    description: "everywhere".to_owned(),
+                }]),
+                ..Default::default()
+            },
+        )
+        .await;
+        assert!(blocked.is_err());
+        assert_eq!(
+            find_postgres("mock_lifecycle").await.unwrap()["ipAllowList"][0]["cidrBlock"],
+            "10.0.0.0/8"
+        );
+
+        PostgresManager::suspend_postgres("mock_lifecycle")
+            .await
+            .unwrap();
+        assert_eq!(
+            find_postgres("mock_lifecycle").await.unwrap()["suspended"],
+            "suspended"
+        );
+        PostgresManager::resume_postgres("mock_lifecycle")
+            .await
+            .unwrap();
+        PostgresManager::restart_postgres("mock_lifecycle")
+            .await
+            .unwrap();
+
+        let info = PostgresManager::get_postgres_connection_info("mock_lifecycle")
+            .await
+            .unwrap();
+        assert!(info.psqlCommand.starts_with("PGPASSWORD="));
+        assert!(!format!("{:?}", info).contains(&info.password));
+    }
+
+    #[tokio::test]
+    async fn test_read_replicas() {
+        let server = shared();
+        server.state().postgres.push(postgres(
+            "dpg-mockreplicated00000",
+            "mock_replicated",
+            "not_suspended",
+        ));
+
+        PostgresManager::add_read_replica("mock_replicated", "mock_replica")
+            .await
+            .unwrap();
+        assert!(
+            PostgresManager::add_read_replica("mock_replicated", "mock_replica")
+                .await
+                .is_err()
+        );
+
+        let replicas = PostgresManager::list_read_replicas("mock_replicated")
+            .await
+            .unwrap();
+        assert_eq!(replicas[0].name, "mock_replica");
+
+        PostgresManager::remove_read_replica("mock_replicated", "mock_replica")
+            .await
+            .unwrap();
+        assert!(PostgresManager::list_read_replicas("mock_replicated")
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_export_and_recover_postgres() {
+        let server = shared();
+        server.state().postgres.push(postgres(
+            "dpg-mockdisaster0000000",
+            "mock_disaster",
+            "not_suspended",
+        ));
+
+        let export = PostgresManager::export_postgres("mock_disaster")
+            .await
+            .unwrap();
+        let exports = PostgresManager::list_postgres_exports("mock_disaster")
+            .await
+            .unwrap();
+        assert_eq!(exports[0]["id"], export["id"]);
+
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("export.sql");
+        let mut progress = vec![];
+        let size = PostgresManager::download_postgres_export(
+            "mock_disaster",
+            None,
+            &path,
+            |done, total| progress.push((done, total)),
+        )
+        .await
+        .unwrap();
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), size);
+        assert_eq!(progress.last(), Some(&(size, Some(size))));
+
+        // Failing to replace the destination, a directory, leaves no partial file behind.
+        let occupied = directory.path().join("occupied");
+        std::fs::create_dir(&occupied).unwrap();
+        let failed =
+            PostgresManager::download_postgres_export("mock_disaster", None, &occupied, |_, _| {})
+                .await;
+        assert!(failed.is_err());
+        assert!(!directory.path().join("occupied.part").exists());
+
+        let info = PostgresManager::get_postgres_recovery_info("mock_disaster")
+            .await
+            .unwrap();
+        assert_eq!(info["recoveryStatus"], "AVAILABLE");
+
+        let restored = PostgresManager::recover_postgres(
+            "mock_disaster",
+            Utc::now() - chrono::Duration::hours(1),
+            Some("mock_disaster_restored"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(restored["name"], "mock_disaster_restored");
+        assert_ne!(restored["id"], "dpg-mockdisaster0000000");
+
+        // Before the instance existed.
+        let too_early = PostgresManager::recover_postgres(
+            "mock_disaster",
+            "2020-01-01T00:00:00Z".parse().unwrap(),
+            None,
+        )
+        .await;
+        assert!(too_early.is_err());
+    }
+}
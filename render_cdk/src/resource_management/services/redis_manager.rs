@@ -0,0 +1,428 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+#![allow(non_snake_case)]
+// [JSON] parsing.
+use serde_json::Value;
+
+// Idiomatic [ERROR] handling.
+use anyhow::{Context, Error, Ok, Result};
+
+// HTTP.
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+
+// [render_cdk] modules.
+use crate::authentication::owner::Info;
+use crate::policy_management::prelude::{PolicyManager, Resource};
+use crate::resource_management::models::redis::{
+    RedisConf, RedisConnectionInfo, RedisFilters, RedisInstance, RedisSpec, RedisUpdate,
+};
+use crate::resource_management::services::service_manager::{
+    ServiceManager, ServiceManagerOperations,
+};
+use crate::state_management::state::State;
+use crate::utils::config::Conf;
+use crate::utils::naming::check_name_collisions;
+use crate::utils::random::GENERATE_UNIQUE_NAME;
+use crate::utils::validation::{Validate, ValidationErrors};
+
+// [DEBUG] utils.
+use crate::logger::prelude::*;
+use crate::utils::stringify::Stringify;
+use crate::{
+    create_get_request, create_patch_request, create_post_request, handle_response,
+    handle_response_data, LOGGER,
+};
+use colored::Colorize;
+
+/// Redis (Key Value) instances after creation.
+#[derive(Debug)]
+pub struct RedisManager;
+
+pub trait RedisManagerOperations {
+    /// Create an instance, validated and checked against the policies like a `[redis]` section.
+    fn create_redis(
+        spec: RedisSpec,
+    ) -> impl std::future::Future<Output = Result<RedisInstance, Error>> + Send;
+
+    /// List the redis instances matching the filters.
+    fn list_redis_instances(
+        filters: RedisFilters,
+        limit: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Change the name, plan, maxmemory policy and/or IP allow list of an instance.
+    fn update_redis(
+        name: &str,
+        update: RedisUpdate,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Suspend an instance.
+    fn suspend_redis(name: &str) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Resume a suspended instance.
+    fn resume_redis(name: &str) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// The connection strings and `redis-cli` command of an instance.
+    fn get_redis_connection_info(
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<RedisConnectionInfo, Error>> + Send;
+}
+
+impl RedisManagerOperations for RedisManager {
+    async fn create_redis(mut spec: RedisSpec) -> Result<RedisInstance, Error> {
+        if spec.ownerId.is_none() {
+            spec.ownerId = Some(Info::whoami().await?.id);
+        }
+        if spec.name.as_deref().is_none_or(str::is_empty) {
+            spec.name = Some(GENERATE_UNIQUE_NAME());
+        }
+        if spec.plan.is_empty() {
+            spec.plan = "starter".to_owned();
+        }
+
+        let config = Conf {
+            redis: Some(spec.clone().into()),
+            ..Default::default()
+        };
+        let errors = config.validate();
+        if !errors.is_empty() {
+            return Err(errors.into());
+        }
+        PolicyManager::check(&Resource::Configuration(&config))?;
+        check_name_collisions(&config).await?;
+
+        post_redis(spec).await
+    }
+
+    async fn list_redis_instances(filters: RedisFilters, limit: &str) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request GET \
+                --url 'https://api.render.com/v1/redis?region=oregon&limit=20' \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let mut api_url = format!("{}{}{}", state.BASE_URL, "/redis?limit=", limit);
+        let query = filters.query();
+        if !query.is_empty() {
+            api_url = format!("{}&{}", api_url, query);
+        }
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+        let response = create_get_request!(client, api_url, api_key)?;
+        handle_response_data!(response, "<list_redis_instances>")
+    }
+
+    async fn update_redis(name: &str, update: RedisUpdate) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request PATCH \
+                --url https://api.render.com/v1/redis/redisId \
+                --header 'Accept: application/json' \
+                --header 'Content-Type: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}' \
+                --data '
+                {
+                    "plan": "standard",
+                    "maxmemoryPolicy": "allkeys_lru"
+                }'
+
+        *****************************************************************/
+
+        let errors = update.validate();
+        if !errors.is_empty() {
+            return Err(errors.into());
+        }
+        PolicyManager::check(&Resource::RedisUpdate(&update))?;
+
+        let id = redis_id(name).await?;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!("{}{}{}", state.BASE_URL, "/redis/", id);
+        let payload = update.stringify();
+
+        // [DEBUG] logs.
+        LOGGER!(
+            "\nProcessing <request> :: <update> -> ",
+            &api_url,
+            LogLevel::WARN
+        );
+        LOGGER!("[PAYLOAD] -> ", &payload, LogLevel::WARN);
+
+        let response = create_patch_request!(client, api_url, api_key, payload, "<update_redis>")?;
+        handle_response!(response, "<update_redis>")
+    }
+
+    async fn suspend_redis(name: &str) -> Result<Value, Error> {
+        redis_action(name, "suspend").await
+    }
+
+    async fn resume_redis(name: &str) -> Result<Value, Error> {
+        redis_action(name, "resume").await
+    }
+
+    async fn get_redis_connection_info(name: &str) -> Result<RedisConnectionInfo, Error> {
+        /*****************************************************
+         *
+            curl --request GET \
+                --url https://api.render.com/v1/redis/redisId/connection-info \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let id = redis_id(name).await?;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!(
+            "{}{}{}{}",
+            state.BASE_URL, "/redis/", id, "/connection-info"
+        );
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+        let response = create_get_request!(client, api_url, api_key)?;
+
+        // Unlike [handle_response!], the response isn't logged since it holds the password.
+        if response.status().is_success() {
+            let result = response.text().await.context("Error parsing response.")?;
+            let info: RedisConnectionInfo = serde_json::from_str(&result)?;
+
+            LOGGER!("<response> -> ", format!("{:#?}", info), LogLevel::SUCCESS);
+
+            Ok(info)
+        } else {
+            let result = response.text().await.context("Error parsing response.")?;
+            let data: Value = serde_json::from_str(&result)?;
+            let message = data["message"]
+                .as_str()
+                .unwrap_or("An error occurred :: Process -> <get_redis_connection_info>");
+
+            LOGGER!(
+                "<response status> -> ",
+                format!("{:#?}", message),
+                LogLevel::CRITICAL
+            );
+
+            Err(anyhow::anyhow!("<Error>: {:#?}", data))
+        }
+    }
+}
+
+/// Creates an instance from a spec that has already been checked, see [RedisManager::create_redis].
+pub(crate) async fn post_redis(spec: RedisSpec) -> Result<RedisInstance, Error> {
+    /*****************************************************
+     *
+        curl --request POST \
+            --url https://api.render.com/v1/redis \
+            --header 'Accept: application/json' \
+            --header 'Content-Type: application/json' \
+            --header 'Authorization: Bearer {{render_api_token_goes_here}}' \
+            --data '
+            {
+                "name": "cache",
+                "plan": "starter",
+                "ownerId": "usr-a1b2c3d4"
+            }'
+
+    *****************************************************************/
+
+    let state = State::init().await;
+    let client = state.CLIENT;
+    let api_key = state.API_KEY;
+    let api_url = format!("{}{}", state.BASE_URL, "/redis");
+    let payload = RedisConf {
+        name: spec.name,
+        ownerId: spec.ownerId.unwrap_or_default(),
+        plan: spec.plan,
+        region: spec.region,
+        maxmemoryPolicy: spec.maxmemoryPolicy,
+        ipAllowList: Some(spec.ipAllowList),
+    }
+    .stringify();
+
+    // [DEBUG] logs.
+    LOGGER!(
+        "\n<request> :: Creating request -> ",
+        &api_url,
+        LogLevel::WARN
+    );
+    LOGGER!("[PAYLOAD] :: -> ", &payload, LogLevel::WARN);
+
+    let response = create_post_request!(client, api_url, api_key, payload, "<redis>")?;
+    let data = handle_response!(response, "<create_redis>")?;
+    let redis: RedisInstance = serde_json::from_value(data)?;
+
+    LOGGER!(
+        "[REDIS] :: Deployment successful. -> ",
+        format!("{:#?}", redis.dashboardUrl),
+        LogLevel::SUCCESS
+    );
+
+    Ok(redis)
+}
+
+/// Suspends or resumes an instance.
+async fn redis_action(name: &str, action: &str) -> Result<Value, Error> {
+    /*****************************************************
+     *
+        curl --request POST \
+            --url https://api.render.com/v1/redis/redisId/suspend \
+            --header 'Accept: application/json' \
+            --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+    *****************************************************************/
+
+    let id = redis_id(name).await?;
+
+    let state = State::init().await;
+    let client = state.CLIENT;
+    let api_key = state.API_KEY;
+    let api_url = format!("{}{}{}{}{}", state.BASE_URL, "/redis/", id, "/", action);
+
+    // [DEBUG] logs.
+    LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+    let response = create_post_request!(client, api_url, api_key, "", "<redis_action>")?;
+    handle_response!(response, "<redis_action>")
+}
+
+pub(crate) async fn redis_id(name: &str) -> Result<String, Error> {
+    let found = ServiceManager::find_redis_instance_by_name(name, "100").await?;
+
+    found
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .map(|item| &item["redis"])
+        .find(|redis| redis["name"] == name)
+        .and_then(|redis| redis["id"].as_str())
+        .map(str::to_owned)
+        .ok_or_else(|| anyhow::anyhow!("Redis instance {:?} not found.", name))
+}
+
+#[cfg(test)]
+mod redis_manager_tests {
+    use super::*;
+    use crate::policy_management::prelude::PolicyReport;
+    use crate::resource_management::models::caching::RedisCidrAllowList;
+    use crate::resource_management::models::enums::{MaxmemoryPolicy, Region};
+    use crate::testing::prelude::*;
+
+    #[tokio::test]
+    async fn test_create_redis() {
+        shared();
+
+        let created = RedisManager::create_redis(RedisSpec {
+            maxmemoryPolicy: Some(MaxmemoryPolicy::NoEviction),
+            ..RedisSpec::new("mock_harness_cache", "")
+        })
+        .await
+        .unwrap();
+        assert!(created.id.starts_with("red-"));
+        assert_eq!(created.plan, "starter");
+        assert_eq!(created.maxmemoryPolicy, Some(MaxmemoryPolicy::NoEviction));
+        assert!(created.dashboardUrl.ends_with(&created.id));
+
+        // The name is already taken.
+        let duplicate = RedisManager::create_redis(RedisSpec::new("mock_harness_cache", "")).await;
+        assert!(duplicate.is_err());
+
+        let invalid =
+            RedisManager::create_redis(RedisSpec::new("mock_invalid_cache", "huge")).await;
+        assert!(invalid.is_err());
+
+        // The spec's eviction policy is validated as part of the `[redis]` section.
+        let invalid = RedisManager::create_redis(RedisSpec {
+            maxmemoryPolicy: Some(MaxmemoryPolicy::from("lru")),
+            ..RedisSpec::new("mock_invalid_cache", "")
+        })
+        .await;
+        let errors = invalid.unwrap_err().downcast::<ValidationErrors>().unwrap();
+        assert_eq!(errors.0[0].field, "redis.maxmemoryPolicy");
+    }
+
+    #[tokio::test]
+    async fn test_redis_lifecycle() {
+        let server = shared();
+        server
+            .state()
+            .redis
+            .push(redis("red-mocklifecycle000000", "mock_cache"));
+
+        let filters = RedisFilters {
+            names: vec!["mock_cache".to_owned()],
+            region: Some(Region::Oregon),
+            ..Default::default()
+        };
+        let found = RedisManager::list_redis_instances(filters, "20")
+            .await
+            .unwrap();
+        assert_eq!(found[0]["redis"]["id"], "red-mocklifecycle000000");
+
+        let updated = RedisManager::update_redis(
+            "mock_cache",
+            RedisUpdate {
+                plan: Some("standard".to_owned()),
+                maxmemoryPolicy: Some(MaxmemoryPolicy::AllKeysLru),
+                ipAllowList: Some(vec![RedisCidrAllowList {
+                    cidrBlock: "10.0.0.0/8".to_owned(),
+                    description: "office".to_owned(),
+                }]),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated["plan"], "standard");
+        assert_eq!(updated["maxmemoryPolicy"], "allkeys_lru");
+
+        let invalid = RedisManager::update_redis(
+            "mock_cache",
+            RedisUpdate {
+                plan: Some("standrd".to_owned()),
+                maxmemoryPolicy: Some(MaxmemoryPolicy::from("lru")),
+                ..Default::default()
+            },
+        )
+        .await;
+        let errors = invalid.unwrap_err().downcast::<ValidationErrors>().unwrap();
+        assert_eq!(errors.0.len(), 2);
+
+        let blocked = RedisManager::update_redis(
+            "mock_cache",
+            RedisUpdate {
+                ipAllowList: Some(vec![RedisCidrAllowList {
+                    cidrBlock: "0.0.0.0/0".to_owned(),
+                    description: "everywhere".to_owned(),
+                }]),
+                ..Default::default()
+            },
+        )
+        .await;
+        let report = blocked.unwrap_err().downcast::<PolicyReport>().unwrap();
+        assert_eq!(report.violations[0].field, "ipAllowList[0].cidrBlock");
+
+        RedisManager::suspend_redis("mock_cache").await.unwrap();
+        RedisManager::resume_redis("mock_cache").await.unwrap();
+
+        let info = RedisManager::get_redis_connection_info("mock_cache")
+            .await
+            .unwrap();
+        assert!(info.redisCLICommand.starts_with("redis-cli"));
+        assert!(!format!("{:?}", info).contains(&info.externalConnectionString));
+    }
+}
@@ -0,0 +1,364 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+#![allow(non_snake_case)]
+// [JSON] parsing.
+use serde_json::Value;
+
+// Idiomatic [ERROR] handling.
+use anyhow::{Context, Error, Ok, Result};
+
+// HTTP.
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+
+// [render_cdk] modules.
+use crate::authentication::owner::Info;
+use crate::resource_management::models::prelude::*;
+use crate::state_management::state::State;
+
+// [DEBUG] utils.
+use crate::logger::prelude::*;
+use crate::utils::stringify::Stringify;
+use crate::{
+    create_delete_request, create_get_request, create_patch_request, create_post_request,
+    handle_response, handle_response_data, LOGGER,
+};
+use colored::Colorize;
+
+/// Credentials for pulling images from private registries, referenced by [Image].
+#[derive(Debug)]
+pub struct RegistryManager;
+
+pub trait RegistryManagerOperations {
+    /// List all registry credentials.
+    fn list_registry_credentials(
+        limit: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// List the registry credentials matching the specified name.
+    fn find_registry_credential_by_name(
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// The id of the registry credential with the specified name.
+    fn registry_credential_id(
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<String, Error>> + Send;
+
+    /// Create a registry credential, under the owner of the API key unless `owner_id` is set.
+    fn create_registry_credential(
+        credential: RegistryCredentialConf,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Replace the registry, name, username and token of a registry credential.
+    fn update_registry_credential(
+        name: &str,
+        credential: RegistryCredentialConf,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+    /// Delete a registry credential.
+    fn delete_registry_credential(
+        name: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+}
+
+impl RegistryManagerOperations for RegistryManager {
+    async fn list_registry_credentials(limit: &str) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request GET \
+                --url 'https://api.render.com/v1/registrycredentials?limit=20' \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!(
+            "{}{}{}",
+            state.BASE_URL, "/registrycredentials?limit=", limit
+        );
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+        let response = create_get_request!(client, api_url, api_key)?;
+        handle_response_data!(response, "<list_registry_credentials>")
+    }
+
+    async fn find_registry_credential_by_name(name: &str) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request GET \
+                --url 'https://api.render.com/v1/registrycredentials?name=dockerhub' \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let query = url::form_urlencoded::Serializer::new(String::new())
+            .append_pair("name", name)
+            .finish();
+        let api_url = format!("{}{}{}", state.BASE_URL, "/registrycredentials?", query);
+
+        // [DEBUG] logs.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+
+        let response = create_get_request!(client, api_url, api_key)?;
+        handle_response_data!(response, "<find_registry_credential_by_name>")
+    }
+
+    async fn registry_credential_id(name: &str) -> Result<String, Error> {
+        let found = RegistryManager::find_registry_credential_by_name(name).await?;
+        let ids: Vec<&str> = found
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .map(credential)
+            .filter(|credential| credential["name"] == name)
+            .filter_map(|credential| credential["id"].as_str())
+            .collect();
+
+        match ids.as_slice() {
+            [id] => Ok(id.to_string()),
+            [] => Err(anyhow::anyhow!("Registry credential {:?} not found.", name)),
+            _ => Err(anyhow::anyhow!(
+                "Found {} registry credentials named {:?}, reference one by id instead.",
+                ids.len(),
+                name
+            )),
+        }
+    }
+
+    async fn create_registry_credential(
+        mut credential: RegistryCredentialConf,
+    ) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request POST \
+                --url https://api.render.com/v1/registrycredentials \
+                --header 'Accept: application/json' \
+                --header 'Content-Type: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}' \
+                --data '
+                {
+                    "registry": "DOCKER",
+                    "name": "dockerhub",
+                    "username": "username",
+                    "authToken": "token",
+                    "ownerId": "usr-a1b2c3d4"
+                }'
+
+        *****************************************************************/
+
+        if credential.owner_id.is_none() {
+            credential.owner_id = Some(Info::whoami().await?.id);
+        }
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!("{}{}", state.BASE_URL, "/registrycredentials");
+
+        // [DEBUG] logs, with the token redacted.
+        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+        LOGGER!("[PAYLOAD] -> ", format!("{:?}", credential), LogLevel::WARN);
+
+        let payload = credential.stringify();
+        let response = create_post_request!(
+            client,
+            api_url,
+            api_key,
+            payload,
+            "<create_registry_credential>"
+        )?;
+        handle_response!(response, "<create_registry_credential>")
+    }
+
+    async fn update_registry_credential(
+        name: &str,
+        mut credential: RegistryCredentialConf,
+    ) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request PATCH \
+                --url https://api.render.com/v1/registrycredentials/registryCredentialId \
+                --header 'Accept: application/json' \
+                --header 'Content-Type: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}' \
+                --data '
+                {
+                    "registry": "DOCKER",
+                    "name": "dockerhub",
+                    "username": "username",
+                    "authToken": "token"
+                }'
+
+        *****************************************************************/
+
+        let id = RegistryManager::registry_credential_id(name).await?;
+        // The owner can't be changed.
+        credential.owner_id = None;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!("{}{}{}", state.BASE_URL, "/registrycredentials/", id);
+
+        // [DEBUG] logs, with the token redacted.
+        LOGGER!(
+            "\nProcessing <request> :: <update> -> ",
+            &api_url,
+            LogLevel::WARN
+        );
+        LOGGER!("[PAYLOAD] -> ", format!("{:?}", credential), LogLevel::WARN);
+
+        let payload = credential.stringify();
+        let response = create_patch_request!(
+            client,
+            api_url,
+            api_key,
+            payload,
+            "<update_registry_credential>"
+        )?;
+        handle_response!(response, "<update_registry_credential>")
+    }
+
+    async fn delete_registry_credential(name: &str) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request DELETE \
+                --url https://api.render.com/v1/registrycredentials/registryCredentialId \
+                --header 'Accept: application/json' \
+                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+        *****************************************************************/
+
+        let id = RegistryManager::registry_credential_id(name).await?;
+
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!("{}{}{}", state.BASE_URL, "/registrycredentials/", id);
+
+        // [DEBUG] logs.
+        LOGGER!(
+            "\nProcessing <request> :: <delete> -> ",
+            &api_url,
+            LogLevel::WARN
+        );
+
+        let response = create_delete_request!(client, api_url, api_key)?;
+        handle_response!(response, "<delete_registry_credential>")
+    }
+}
+
+/// Replaces registry credential names in a template with their ids.
+pub async fn resolve_registry_credentials(mut template: Template) -> Result<Template, Error> {
+    if let Some(image) = template.image.as_mut() {
+        image.registry_credential_id = resolve(&image.registry_credential_id).await?;
+    }
+
+    let env_details = template
+        .service_details
+        .as_mut()
+        .and_then(|details| details.env_specific_details.as_mut());
+    if let Some(reference) = env_details.and_then(|env| env.registry_credential_id.as_mut()) {
+        *reference = resolve(reference).await?;
+    }
+
+    Ok(template)
+}
+
+async fn resolve(reference: &str) -> Result<String, Error> {
+    if reference.is_empty() || reference.starts_with(REGISTRY_CREDENTIAL_ID_PREFIX) {
+        Ok(reference.to_owned())
+    } else {
+        RegistryManager::registry_credential_id(reference).await
+    }
+}
+
+/// List items are either the credential or wrapped along with a cursor.
+fn credential(item: &Value) -> &Value {
+    item.get("registryCredential").unwrap_or(item)
+}
+
+#[cfg(test)]
+mod registry_manager_tests {
+    use super::*;
+    use crate::testing::prelude::*;
+
+    #[tokio::test]
+    async fn test_registry_credential_lifecycle() {
+        shared();
+
+        let created = RegistryManager::create_registry_credential(RegistryCredentialConf::new(
+            Registry::Github,
+            "mock_ghcr",
+            "octocat",
+            "ghp_mockToken",
+        ))
+        .await
+        .unwrap();
+        assert_eq!(created["registry"], "GITHUB");
+        assert!(created.get("authToken").is_none());
+
+        let id = RegistryManager::registry_credential_id("mock_ghcr")
+            .await
+            .unwrap();
+        assert_eq!(created["id"], id.as_str());
+
+        let updated = RegistryManager::update_registry_credential(
+            "mock_ghcr",
+            RegistryCredentialConf::new(Registry::Github, "mock_ghcr", "hubot", "ghp_rotated"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(updated["username"], "hubot");
+
+        RegistryManager::delete_registry_credential("mock_ghcr")
+            .await
+            .unwrap();
+        assert!(RegistryManager::registry_credential_id("mock_ghcr")
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_resolve_registry_credentials() {
+        shared();
+
+        let template = Template {
+            image: Some(Image {
+                owner_id: MOCK_OWNER_ID.to_owned(),
+                registry_credential_id: "dockerhub".to_owned(),
+                image_path: "docker.io/render-cdk/whoami:latest".to_owned(),
+            }),
+            ..Default::default()
+        };
+        let template = resolve_registry_credentials(template).await.unwrap();
+        assert_eq!(
+            template.image.unwrap().registry_credential_id,
+            MOCK_REGISTRY_CREDENTIAL_ID
+        );
+
+        // Ids are kept as is.
+        let template = Template {
+            image: Some(Image {
+                registry_credential_id: "rcr-unknown".to_owned(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let template = resolve_registry_credentials(template).await.unwrap();
+        assert_eq!(
+            template.image.unwrap().registry_credential_id,
+            "rcr-unknown"
+        );
+    }
+}
@@ -15,25 +15,29 @@ use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
 // [render_cdk] modules.
 use crate::authentication::owner::Info;
 use crate::authentication::owner::*;
+use crate::cost_management::prelude::estimate_cost;
 use crate::environment_management::prelude::EnvironmentManager;
-use crate::resource_management::models::postgres::PostgresConf;
+use crate::policy_management::prelude::{PolicyManager, Resource};
 use crate::resource_management::models::prelude::*;
-use crate::resource_management::models::redis::RedisConf;
+use crate::resource_management::services::postgres_manager::post_postgres;
+use crate::resource_management::services::redis_manager::post_redis;
+use crate::resource_management::services::registry_manager::resolve_registry_credentials;
 use crate::state_management::state::{Owner, State};
 use crate::utils::config::Conf;
+use crate::utils::naming::check_name_collisions;
 use crate::utils::request_manager;
+use crate::utils::validation::Validate;
 
 // [DEBUG] utils.
 use crate::logger::prelude::*;
 use crate::utils::stringify::Stringify;
 use crate::{
-    create_delete_request, create_get_request, create_post_request, handle_response,
-    handle_response_data, LOGGER,
+    create_delete_request, create_get_request, create_patch_request, create_post_request,
+    handle_response, handle_response_data, LOGGER,
 };
 use colored::Colorize;
 
-// Predefined [CONSTANTS].
-const BASE_URL: &str = "https://api.render.com/v1";
+use std::time::Instant;
 
 #[derive(Debug)]
 pub struct ServiceManager;
@@ -80,7 +84,7 @@ pub trait ServiceManagerOperations {
     /// Find a service by name and type.
     fn find_service_by_name_and_type(
         service_name: &str,
-        service_type: &str,
+        service_type: impl Into<ServiceType> + Send,
     ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
 
     /// List services that match specified region.
@@ -101,15 +105,41 @@ pub trait ServiceManagerOperations {
         deployment_config: Template,
     ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
 
+    /// Create and deploy a service under a specific owner/workspace.
+    fn create_service_for_owner(
+        deployment_config: Template,
+        owner_id: &str,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
     /// Deploy configuration.
     fn deploy_configuration(
         config_path: &str,
-    ) -> impl std::future::Future<Output = Result<String, Error>> + Send;
+    ) -> impl std::future::Future<Output = Result<DeploymentReport, Error>> + Send;
+
+    /// Deploy configuration under a specific owner/workspace.
+    fn deploy_configuration_for_owner(
+        config_path: &str,
+        owner_id: &str,
+    ) -> impl std::future::Future<Output = Result<DeploymentReport, Error>> + Send;
+
+    /// Deploy configuration, stopping and/or rolling back on failure as configured.
+    /// A failed resource fails the deployment with the [DeploymentReport] as the error.
+    fn deploy_configuration_with_options(
+        config_path: &str,
+        options: DeploymentOptions,
+    ) -> impl std::future::Future<Output = Result<DeploymentReport, Error>> + Send;
+
+    /// Update the settings of an existing service.
+    fn update_service(
+        service_name: &str,
+        service_type: ServiceType,
+        update: ServiceUpdate,
+    ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
 
     /// Deleting a service.
     fn delete_service(
         service_name: &str,
-        service_type: &str,
+        service_type: impl Into<ServiceType> + Send,
     ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
 
     /// Delete postgres instance.
@@ -135,9 +165,10 @@ impl ServiceManagerOperations for ServiceManager {
 
         *****************************************************************/
 
-        let client = State::init().await.CLIENT;
-        let api_key = State::init().await.API_KEY;
-        let api_url = format!("{}{}{}", BASE_URL, "/services?limit=", limit);
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let api_url = format!("{}{}{}", state.BASE_URL, "/services?limit=", limit);
 
         // [DEBUG] logs.
         LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
@@ -188,11 +219,12 @@ impl ServiceManagerOperations for ServiceManager {
 
         *****************************************************************/
 
-        let client = State::init().await.CLIENT;
-        let api_key = State::init().await.API_KEY;
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
         let api_url = format!(
             "{}{}{}{}{}",
-            BASE_URL, "/postgres?includeReplicas=", include_replicas, "&limit=", limit
+            state.BASE_URL, "/postgres?includeReplicas=", include_replicas, "&limit=", limit
         );
 
         // [DEBUG] logs.
@@ -248,11 +280,12 @@ impl ServiceManagerOperations for ServiceManager {
 
         *****************************************************************/
 
-        let client = State::init().await.CLIENT;
-        let api_key = State::init().await.API_KEY;
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
         let api_url = format!(
             "{}{}{}{}{}{}{}",
-            BASE_URL,
+            state.BASE_URL,
             "/postgres?name=",
             name,
             "&includeReplicas=",
@@ -314,11 +347,12 @@ impl ServiceManagerOperations for ServiceManager {
 
         *****************************************************************/
 
-        let client = State::init().await.CLIENT;
-        let api_key = State::init().await.API_KEY;
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
         let api_url = format!(
             "{}{}{}{}{}{}{}",
-            BASE_URL,
+            state.BASE_URL,
             "/postgres?suspended=",
             status,
             "&includeReplicas=",
@@ -375,11 +409,12 @@ impl ServiceManagerOperations for ServiceManager {
 
         *****************************************************************/
 
-        let client = State::init().await.CLIENT;
-        let api_key = State::init().await.API_KEY;
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
         let api_url = format!(
             "{}{}{}{}{}",
-            BASE_URL, "/redis?name=", name, "&limit=", limit
+            state.BASE_URL, "/redis?name=", name, "&limit=", limit
         );
 
         // [DEBUG] logs.
@@ -401,11 +436,12 @@ impl ServiceManagerOperations for ServiceManager {
 
         *****************************************************************/
 
-        let client = State::init().await.CLIENT;
-        let api_key = State::init().await.API_KEY;
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
         let api_url = format!(
             "{}{}{}{}{}",
-            BASE_URL, "/services?suspended=", service_status, "&limit=", limit
+            state.BASE_URL, "/services?suspended=", service_status, "&limit=", limit
         );
 
         // [DEBUG] logs.
@@ -419,7 +455,7 @@ impl ServiceManagerOperations for ServiceManager {
     /// Reqquired arguments: <service_type>
     async fn find_service_by_name_and_type(
         service_name: &str,
-        service_type: &str,
+        service_type: impl Into<ServiceType> + Send,
     ) -> Result<Value, Error> {
         /*****************************************************
          *
@@ -430,11 +466,13 @@ impl ServiceManagerOperations for ServiceManager {
 
         *****************************************************************/
 
-        let client = State::init().await.CLIENT;
-        let api_key = State::init().await.API_KEY;
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
+        let service_type: ServiceType = service_type.into();
         let api_url = format!(
             "{}{}{}{}{}",
-            BASE_URL, "/services?name=", service_name, "&type=", service_type
+            state.BASE_URL, "/services?name=", service_name, "&type=", service_type
         );
 
         // [DEBUG] logs.
@@ -455,11 +493,12 @@ impl ServiceManagerOperations for ServiceManager {
 
         *****************************************************************/
 
-        let client = State::init().await.CLIENT;
-        let api_key = State::init().await.API_KEY;
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
         let api_url = format!(
             "{}{}{}{}{}",
-            BASE_URL, "/services?region=", service_region, "&limit=", limit
+            state.BASE_URL, "/services?region=", service_region, "&limit=", limit
         );
 
         // [DEBUG] logs.
@@ -480,11 +519,12 @@ impl ServiceManagerOperations for ServiceManager {
 
         *****************************************************************/
 
-        let client = State::init().await.CLIENT;
-        let api_key = State::init().await.API_KEY;
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = state.API_KEY;
         let api_url = format!(
             "{}{}{}{}{}",
-            BASE_URL, "/services?env=", service_env, "&limit=", limit
+            state.BASE_URL, "/services?env=", service_env, "&limit=", limit
         );
 
         // [DEBUG] logs.
@@ -496,212 +536,228 @@ impl ServiceManagerOperations for ServiceManager {
 
     /// Creating services.
     async fn create_service(deployment_config: Template) -> Result<Value, Error> {
-        /// Currently supported - Github(https://github.com/username/reponame.git)
-        /******************************************************
-         *
-            curl --request POST \
-                --url https://api.render.com/v1/services \
-                --header 'Accept: application/json' \
-                --header 'Content-Type: application/json' \
-                --header 'Authorization: Bearer {{render_api_token_goes_here}}'
-                --data '
-                {
-                    "type": "static_site",
-                    "autoDeploy": "yes",
-                    "serviceDetails": {
-                        "pullRequestPreviewsEnabled": "no"
-                },
-                    "name": "test",
-                    "ownerId": "test",
-                    "repo": "httpe",
-                    "rootDir": "./",
-                    "envVars": [
-                        {
-                        "key": "EXAMPLE",
-                        "value": "EXAMPLE"
-                        }
-                    ]
-                }'
+        let owner_id = Info::whoami().await?.id;
+        ServiceManager::create_service_for_owner(deployment_config, &owner_id).await
+    }
 
-        **************************************************************/
-        let client = State::init().await.CLIENT;
-        let api_key = State::init().await.API_KEY;
-        let api_url = format!("{}{}", BASE_URL, "/services");
-        let payload = Base {
-            type_: deployment_config.type_,
-            name: deployment_config.name,
-            repo: deployment_config.repo,
-            owner_id: Info::get_owner_id().await,
-            auto_deploy: deployment_config.auto_deploy,
-            branch: deployment_config.branch,
-            image: deployment_config.image,
-            build_filter: deployment_config.build_filter,
-            root_dir: deployment_config.root_dir,
-            env_vars: deployment_config.env_vars,
-            secret_files: deployment_config.secret_files,
-            service_details: deployment_config.service_details,
-            health_check_path: deployment_config.health_check_path,
-            autoscaling: deployment_config.autoscaling,
+    async fn create_service_for_owner(
+        deployment_config: Template,
+        owner_id: &str,
+    ) -> Result<Value, Error> {
+        // Reject invalid templates before the round trip.
+        let errors = deployment_config.validate();
+        if !errors.is_empty() {
+            LOGGER!(
+                "[TEMPLATE] :: Validation failed. -> ",
+                errors.to_string(),
+                LogLevel::CRITICAL
+            );
+            return Err(errors.into());
         }
-        .stringify();
 
-        // [DEBUG] logs.
-        LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
-        LOGGER!("[PAYLOAD] -> ", &payload, LogLevel::WARN);
+        // Reject templates violating the [POLICY].
+        PolicyManager::check(&Resource::Service(&deployment_config))?;
+
+        // Reject names already in use.
+        let config = Conf {
+            services: vec![deployment_config.clone()],
+            ..Default::default()
+        };
+        check_name_collisions(&config).await?;
 
-        let response = create_post_request!(client, api_url, api_key, payload, "<create_service>")?;
-        handle_response!(response, "<create_service>")
+        LOGGER!(
+            "[COST] :: ",
+            estimate_cost(&Conf::default(), std::slice::from_ref(&deployment_config)).to_string(),
+            LogLevel::WARN
+        );
+
+        post_service(deployment_config, owner_id).await
     }
 
-    async fn deploy_configuration(config_path: &str) -> Result<String, Error> {
-        let state = State::init().await;
-        let client = state.CLIENT;
-        let api_key = state.API_KEY;
-        let CONFIG = Conf::read_configuration_file(config_path).unwrap();
+    async fn deploy_configuration(config_path: &str) -> Result<DeploymentReport, Error> {
+        ServiceManager::deploy_configuration_with_options(config_path, DeploymentOptions::default())
+            .await
+    }
 
-        // Authorization.
-        let owner_id = Info::get_owner_id().await;
+    async fn deploy_configuration_for_owner(
+        config_path: &str,
+        owner_id: &str,
+    ) -> Result<DeploymentReport, Error> {
+        let options = DeploymentOptions {
+            owner_id: Some(owner_id.to_owned()),
+            ..Default::default()
+        };
+        ServiceManager::deploy_configuration_with_options(config_path, options).await
+    }
 
-        // [CONFIG] validation.
-        let mut results = Vec::new();
+    async fn deploy_configuration_with_options(
+        config_path: &str,
+        options: DeploymentOptions,
+    ) -> Result<DeploymentReport, Error> {
+        let CONFIG = Conf::read_configuration_file(config_path)?;
+
+        // Reject configurations violating the [POLICY].
+        PolicyManager::check(&Resource::Configuration(&CONFIG))?;
+        for service in &CONFIG.services {
+            PolicyManager::check(&Resource::Service(service))?;
+        }
 
-        // [POSTGRES]
-        if CONFIG.database.is_some() {
-            let api_url = format!("{}{}", BASE_URL, "/postgres");
-
-            let payload = PostgresConf {
-                databaseName: CONFIG.database.clone().unwrap().databaseName,
-                databaseUser: CONFIG.database.clone().unwrap().databaseUser,
-                enableHighAvailability: CONFIG.database.clone().unwrap().enableHighAvailability,
-                plan: CONFIG.database.clone().unwrap().plan,
-                version: CONFIG.database.clone().unwrap().version,
-                name: CONFIG.database.clone().unwrap().name,
-                ownerId: owner_id.clone(),
-                ipAllowList: Some(CONFIG.database.clone().unwrap().cidrBlocks),
-            }
-            .stringify();
+        // Reject names already in use.
+        check_name_collisions(&CONFIG).await?;
+
+        let cost = estimate_cost(&CONFIG, &CONFIG.services);
+        LOGGER!("[COST] :: ", cost.to_string(), LogLevel::WARN);
 
+        if CONFIG.database.is_none() && CONFIG.redis.is_none() && CONFIG.services.is_empty() {
             LOGGER!(
-                "\n<request> :: Creating request -> ",
-                &api_url,
+                "[INFO] :: No configuration to process. -> ",
+                "SKIPPED",
                 LogLevel::WARN
             );
+            return Err(anyhow::anyhow!("No configuration to process."));
+        }
 
-            LOGGER!("[PAYLOAD] :: -> ", &payload, LogLevel::WARN);
-
-            let response = create_post_request!(client, api_url, api_key, payload, "<postgres>")?;
-
-            if response.status().is_success() {
-                let result = response.text().await.context("Error parsing response.")?;
+        // Authorization.
+        let owner_id = match &options.owner_id {
+            Some(owner_id) => owner_id.clone(),
+            None => Info::whoami().await?.id,
+        };
 
-                let data: Value = serde_json::from_str(&result)?;
-                let dashboard_url = data
-                    .get("dashboardUrl")
-                    .unwrap()
-                    .as_str()
-                    .expect("Failed to extract [dashboardUrl] from <response>.");
+        let started = Instant::now();
+        let mut report = DeploymentReport {
+            cost: Some(cost),
+            ..Default::default()
+        };
 
-                LOGGER!(
-                    "[POSTGRES] :: Deployment successful. -> ",
-                    format!("{:#?}", dashboard_url),
-                    LogLevel::SUCCESS
-                );
-
-                results.push(Ok(result));
-            } else {
-                let result = response
-                    .text()
-                    .await
-                    .context("Error parsing response :: <deploy_configuration>")?;
+        // [POSTGRES]
+        if let Some(database) = CONFIG.database.clone() {
+            let name = database.name.clone().unwrap_or_default();
+            let spec = PostgresSpec {
+                ownerId: Some(owner_id.clone()),
+                ..database.into()
+            };
+            let deploy = async {
+                let postgres = post_postgres(spec).await?;
+                Ok((postgres.id, postgres.dashboardUrl))
+            };
+            deploy_resource(&mut report, &options, ResourceKind::Postgres, name, deploy).await;
+        }
 
-                let data: Value = serde_json::from_str(&result)?;
+        // [REDIS]
+        if let Some(redis) = CONFIG.redis.clone() {
+            let name = redis.name.clone().unwrap_or_default();
+            let spec = RedisSpec {
+                ownerId: Some(owner_id.clone()),
+                ..redis.into()
+            };
+            let deploy = async {
+                let redis = post_redis(spec).await?;
+                Ok((redis.id, redis.dashboardUrl))
+            };
+            deploy_resource(&mut report, &options, ResourceKind::Redis, name, deploy).await;
+        }
 
-                let message = data["message"]
+        // [SERVICES]
+        for service in CONFIG.services.iter().cloned() {
+            let kind = ResourceKind::Service(service.type_.clone());
+            let name = service.name.clone();
+            let deploy = async {
+                let data = post_service(service, &owner_id).await?;
+                let id = data["service"]["id"]
                     .as_str()
-                    .unwrap_or("An error occured :: Process -> <list_all_services>");
-
-                LOGGER!(
-                    "[POSTGRES] :: Deployment status :: -> ",
-                    format!("{:#?}", message),
-                    LogLevel::CRITICAL
-                );
-
-                results.push(Err(anyhow::anyhow!("<Error>: {:#?}", message)));
-            }
+                    .unwrap_or_default()
+                    .to_owned();
+                let dashboard_url = data["service"]["dashboardUrl"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_owned();
+                Ok((id, dashboard_url))
+            };
+            deploy_resource(&mut report, &options, kind, name, deploy).await;
         }
 
-        // [REDIS]
-        if CONFIG.redis.is_some() {
-            let api_url = format!("{}{}", BASE_URL, "/redis");
-
-            let payload = RedisConf {
-                name: CONFIG.redis.clone().unwrap().name,
-                ownerId: owner_id.clone(),
-                plan: CONFIG.redis.clone().unwrap().plan,
-                ipAllowList: Some(CONFIG.redis.clone().unwrap().cidrBlocks),
-            }
-            .stringify();
-
-            LOGGER!(
-                "\n<request> :: Creating request -> ",
-                &api_url,
-                LogLevel::WARN
-            );
+        if options.rollback && !report.is_success() {
+            rollback(&mut report).await;
+        }
+        report.duration_ms = started.elapsed().as_millis() as u64;
 
-            LOGGER!("[PAYLOAD] :: -> ", &payload, LogLevel::WARN);
+        if report.is_success() {
+            LOGGER!("[REPORT] :: ", report.to_string(), LogLevel::SUCCESS);
+            Ok(report)
+        } else {
+            LOGGER!("[REPORT] :: ", report.to_string(), LogLevel::CRITICAL);
+            Err(report.into())
+        }
+    }
 
-            let response = create_post_request!(client, api_url, api_key, payload, "<redis>")?;
+    /// Updating services.
+    async fn update_service(
+        service_name: &str,
+        service_type: ServiceType,
+        update: ServiceUpdate,
+    ) -> Result<Value, Error> {
+        /*****************************************************
+         *
+            curl --request PATCH \
+             --url https://api.render.com/v1/services/serviceId \
+             --header 'accept: application/json' \
+             --header 'content-type: application/json' \
+             --header 'Authorization: Bearer {{render_api_token_goes_here}}' \
+             --data '
+                {
+                    "serviceDetails": {
+                        "schedule": "0 3 * * *"
+                    }
+                }'
 
-            if response.status().is_success() {
-                let result = response.text().await.context("Error parsing response.")?;
+        *****************************************************************/
 
-                let data: Value = serde_json::from_str(&result)?;
+        let errors = update.validate();
+        if !errors.is_empty() {
+            return Err(errors.into());
+        }
+        PolicyManager::check(&Resource::ServiceUpdate(&update))?;
 
-                LOGGER!(
-                    "[REDIS] :: Deployment successful. -> ",
-                    format!("{:#?}", data),
-                    LogLevel::SUCCESS
-                );
-                results.push(Ok(result));
-            } else {
-                let result = response
-                    .text()
-                    .await
-                    .context("Error parsing response :: <deploy_configuration>")?;
+        let service =
+            ServiceManager::find_service_by_name_and_type(service_name, service_type).await?;
 
-                let data: Value = serde_json::from_str(&result)?;
+        // Retrieve <service_id>.
+        let service_id = service[0]["service"]["id"].as_str();
 
-                let message = data["message"]
-                    .as_str()
-                    .unwrap_or("An error occured :: Process -> <list_all_services>");
+        match service_id {
+            Some(id) => {
+                let state = State::init().await;
+                let client = state.CLIENT;
+                let api_key = state.API_KEY;
+                let service_url = format!("{}{}{}", state.BASE_URL, "/services/", id);
+                let payload = update.stringify();
 
+                // [DEBUG] logs.
                 LOGGER!(
-                    "[REDIS] :: Deployment status :: -> ",
-                    format!("{:#?}", message),
-                    LogLevel::CRITICAL
+                    "\nProcessing <request> :: <update> -> ",
+                    &service_url,
+                    LogLevel::WARN
                 );
-
-                results.push(Err(anyhow::anyhow!("<Error>: {:#?}", message)));
+                LOGGER!("[PAYLOAD] -> ", &payload, LogLevel::WARN);
+
+                let response = create_patch_request!(
+                    client,
+                    service_url,
+                    api_key,
+                    payload,
+                    "<update_service>"
+                )?;
+                handle_response!(response, "<update_service>")
             }
-        }
-
-        if results.is_empty() {
-            LOGGER!(
-                "[INFO] :: No configuration to process. -> ",
-                "SKIPPED",
-                LogLevel::WARN
-            );
-            Err(anyhow::anyhow!("No configuration to process."))
-        } else {
-            results
-                .into_iter()
-                .find(|r| r.is_err())
-                .unwrap_or_else(|| Ok("All deployments successful".to_string()))
+            None => Err(anyhow::anyhow!("Service Id not found.")),
         }
     }
 
     /// Deleting services.
-    async fn delete_service(service_name: &str, service_type: &str) -> Result<Value, Error> {
+    async fn delete_service(
+        service_name: &str,
+        service_type: impl Into<ServiceType> + Send,
+    ) -> Result<Value, Error> {
         /*****************************************************
          *
             curl --request DELETE \
@@ -719,9 +775,10 @@ impl ServiceManagerOperations for ServiceManager {
 
         match service_id {
             Some(id) => {
-                let client = State::init().await.CLIENT;
-                let api_key = State::init().await.API_KEY;
-                let service_url = format!("{}{}{}", BASE_URL, "/services/", id);
+                let state = State::init().await;
+                let client = state.CLIENT;
+                let api_key = state.API_KEY;
+                let service_url = format!("{}{}{}", state.BASE_URL, "/services/", id);
 
                 // [DEBUG] logs.
                 LOGGER!(
@@ -756,9 +813,10 @@ impl ServiceManagerOperations for ServiceManager {
 
         match postgres_id {
             Some(id) => {
-                let client = State::init().await.CLIENT;
-                let api_key = State::init().await.API_KEY;
-                let postgres_url = format!("{}{}{}", BASE_URL, "/postgres/", id);
+                let state = State::init().await;
+                let client = state.CLIENT;
+                let api_key = state.API_KEY;
+                let postgres_url = format!("{}{}{}", state.BASE_URL, "/postgres/", id);
 
                 // [DEBUG] logs.
                 LOGGER!(
@@ -792,9 +850,10 @@ impl ServiceManagerOperations for ServiceManager {
 
         match redis_id {
             Some(id) => {
-                let client = State::init().await.CLIENT;
-                let api_key = State::init().await.API_KEY;
-                let redis_url = format!("{}{}{}", BASE_URL, "/redis/", id);
+                let state = State::init().await;
+                let client = state.CLIENT;
+                let api_key = state.API_KEY;
+                let redis_url = format!("{}{}{}", state.BASE_URL, "/redis/", id);
 
                 // [DEBUG] logs.
                 LOGGER!(
@@ -810,3 +869,342 @@ impl ServiceManagerOperations for ServiceManager {
         }
     }
 }
+
+/// The id of a service, see [ServiceManager::find_service_by_name_and_type].
+pub(crate) async fn service_id(
+    service_name: &str,
+    service_type: ServiceType,
+) -> Result<String, Error> {
+    let service = ServiceManager::find_service_by_name_and_type(service_name, service_type).await?;
+
+    service[0]["service"]["id"]
+        .as_str()
+        .map(str::to_owned)
+        .ok_or_else(|| anyhow::anyhow!("Service {:?} not found.", service_name))
+}
+
+/// Creates a service from a template that has already been checked, see [ServiceManager::create_service_for_owner].
+pub(crate) async fn post_service(
+    deployment_config: Template,
+    owner_id: &str,
+) -> Result<Value, Error> {
+    /// Currently supported - Github(https://github.com/username/reponame.git)
+    /******************************************************
+     *
+        curl --request POST \
+            --url https://api.render.com/v1/services \
+            --header 'Accept: application/json' \
+            --header 'Content-Type: application/json' \
+            --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+            --data '
+            {
+                "type": "static_site",
+                "autoDeploy": "yes",
+                "serviceDetails": {
+                    "pullRequestPreviewsEnabled": "no"
+            },
+                "name": "test",
+                "ownerId": "test",
+                "repo": "httpe",
+                "rootDir": "./",
+                "envVars": [
+                    {
+                    "key": "EXAMPLE",
+                    "value": "EXAMPLE"
+                    }
+                ]
+            }'
+
+    **************************************************************/
+    // Registry credentials can be referenced by name.
+    let deployment_config = resolve_registry_credentials(deployment_config).await?;
+
+    let state = State::init().await;
+    let client = state.CLIENT;
+    let api_key = state.API_KEY;
+    let api_url = format!("{}{}", state.BASE_URL, "/services");
+    let payload = Base {
+        type_: deployment_config.type_,
+        name: deployment_config.name,
+        repo: deployment_config.repo,
+        owner_id: owner_id.to_owned(),
+        auto_deploy: deployment_config.auto_deploy,
+        branch: deployment_config.branch,
+        image: deployment_config.image,
+        build_filter: deployment_config.build_filter,
+        root_dir: deployment_config.root_dir,
+        env_vars: deployment_config.env_vars,
+        secret_files: deployment_config.secret_files,
+        service_details: deployment_config.service_details,
+        health_check_path: deployment_config.health_check_path,
+        autoscaling: deployment_config.autoscaling,
+    }
+    .stringify();
+
+    // [DEBUG] logs.
+    LOGGER!("\nProcessing <request> -> ", &api_url, LogLevel::WARN);
+    LOGGER!("[PAYLOAD] -> ", &payload, LogLevel::WARN);
+
+    let response = create_post_request!(client, api_url, api_key, payload, "<create_service>")?;
+    handle_response!(response, "<create_service>")
+}
+
+/// Creates a single resource of a configuration, unless an earlier one failed with [DeploymentOptions::fail_fast].
+async fn deploy_resource(
+    report: &mut DeploymentReport,
+    options: &DeploymentOptions,
+    kind: ResourceKind,
+    name: String,
+    deploy: impl std::future::Future<Output = Result<(String, String), Error>>,
+) {
+    let mut outcome = ResourceOutcome {
+        kind,
+        name,
+        status: DeploymentStatus::Skipped,
+        id: None,
+        dashboard_url: None,
+        duration_ms: 0,
+        error: None,
+    };
+
+    if options.fail_fast && !report.is_success() {
+        report.resources.push(outcome);
+        return;
+    }
+
+    let started = Instant::now();
+    match deploy.await {
+        Err(error) => {
+            outcome.status = DeploymentStatus::Failed;
+            outcome.error = Some(error.to_string());
+        }
+        Result::Ok((id, dashboard_url)) => {
+            outcome.status = DeploymentStatus::Created;
+            outcome.id = Some(id).filter(|id| !id.is_empty());
+            outcome.dashboard_url = Some(dashboard_url).filter(|url| !url.is_empty());
+        }
+    }
+    outcome.duration_ms = started.elapsed().as_millis() as u64;
+
+    report.resources.push(outcome);
+}
+
+/// Deletes the resources created so far, most recent first.
+async fn rollback(report: &mut DeploymentReport) {
+    for resource in report.resources.iter_mut().rev() {
+        if resource.status != DeploymentStatus::Created {
+            continue;
+        }
+
+        let result = match &resource.id {
+            Some(id) => delete_resource(&resource.kind, id).await,
+            None => Err(anyhow::anyhow!(
+                "{} {:?} has no id.",
+                resource.kind,
+                resource.name
+            )),
+        };
+
+        if let Err(error) = result {
+            resource.status = DeploymentStatus::RollbackFailed;
+            resource.error = Some(error.to_string());
+        } else {
+            resource.status = DeploymentStatus::RolledBack;
+        }
+    }
+}
+
+/// Deletes a resource by id, another resource may have the same name.
+async fn delete_resource(kind: &ResourceKind, id: &str) -> Result<Value, Error> {
+    /*****************************************************
+     *
+        curl --request DELETE \
+             --url https://api.render.com/v1/postgres/postgresId \
+             --header 'accept: application/json' \
+             --header 'Authorization: Bearer {{render_api_token_goes_here}}'
+
+    *****************************************************************/
+
+    let path = match kind {
+        ResourceKind::Postgres => "/postgres/",
+        ResourceKind::Redis => "/redis/",
+        ResourceKind::Service(_) => "/services/",
+    };
+
+    let state = State::init().await;
+    let client = state.CLIENT;
+    let api_key = state.API_KEY;
+    let api_url = format!("{}{}{}", state.BASE_URL, path, id);
+
+    // [DEBUG] logs.
+    LOGGER!(
+        "\nProcessing <request> :: <delete> -> ",
+        &api_url,
+        LogLevel::WARN
+    );
+
+    let response = create_delete_request!(client, api_url, api_key)?;
+    handle_response!(response, "<rollback>")
+}
+
+#[cfg(test)]
+mod service_manager_tests {
+    use super::*;
+    use crate::resource_management::models::caching::CacheConf;
+    use crate::resource_management::models::storage::DatabaseConf;
+    use crate::testing::prelude::*;
+
+    /// A service failing to deploy, since its registry credential doesn't exist.
+    fn failing_service(name: &str) -> Template {
+        let mut service = web_service(name);
+        let details = service.service_details.as_mut().unwrap();
+        details.runtime = Some(Runtime::Docker);
+        details.env_specific_details = Some(EnvSpecificDetails {
+            registry_credential_id: Some("mock_missing_credential".to_owned()),
+            ..Default::default()
+        });
+        service
+    }
+
+    /// The file is removed along with the returned directory.
+    fn write_config(config: Conf) -> (tempfile::TempDir, String) {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("config.toml");
+        let path = path.to_str().unwrap().to_owned();
+        config.write_configuration_file(&path).unwrap();
+        (directory, path)
+    }
+
+    #[tokio::test]
+    async fn test_create_service_rejects_existing_name() {
+        let server = shared();
+
+        let error = ServiceManager::create_service(web_service("whoami"))
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("already exist"));
+        assert_eq!(
+            server
+                .state()
+                .services
+                .iter()
+                .filter(|s| s["name"] == "whoami")
+                .count(),
+            1
+        );
+
+        // Service types can still be passed as strings.
+        let found = ServiceManager::find_service_by_name_and_type("whoami", "web_service")
+            .await
+            .unwrap();
+        assert_eq!(found[0]["service"]["name"], "whoami");
+    }
+
+    #[tokio::test]
+    async fn test_deploy_configuration_rollback() {
+        let server = shared();
+        let config = Conf {
+            database: Some(DatabaseConf {
+                databaseName: None,
+                databaseUser: None,
+                enableHighAvailability: false,
+                plan: "starter".to_owned(),
+                version: "16".to_owned(),
+                name: Some("mock_rollback_db".to_owned()),
+                region: None,
+                cidrBlocks: vec![],
+            }),
+            redis: Some(CacheConf {
+                name: Some("mock_rollback_cache".to_owned()),
+                plan: "starter".to_owned(),
+                region: None,
+                maxmemoryPolicy: None,
+                cidrBlocks: vec![],
+            }),
+            services: vec![failing_service("mock_rollback_api")],
+            ..Default::default()
+        };
+        let (_directory, path) = write_config(config);
+
+        let options = DeploymentOptions {
+            rollback: true,
+            ..Default::default()
+        };
+        let error = ServiceManager::deploy_configuration_with_options(&path, options)
+            .await
+            .unwrap_err();
+        let report = error.downcast_ref::<DeploymentReport>().unwrap();
+
+        let statuses: Vec<DeploymentStatus> = report.resources.iter().map(|r| r.status).collect();
+        assert_eq!(
+            statuses,
+            [
+                DeploymentStatus::RolledBack,
+                DeploymentStatus::RolledBack,
+                DeploymentStatus::Failed
+            ]
+        );
+        assert!(report.resources[0]
+            .id
+            .as_deref()
+            .unwrap()
+            .starts_with("dpg-"));
+        assert!(report.resources[2].error.is_some());
+
+        // Deleted by id, not looked up by name.
+        let id = report.resources[0].id.as_deref().unwrap();
+        assert_eq!(server.hits("DELETE", &format!("/postgres/{}", id)), 1);
+
+        let state = server.state();
+        assert!(!state
+            .postgres
+            .iter()
+            .any(|p| p["name"] == "mock_rollback_db"));
+        assert!(!state
+            .redis
+            .iter()
+            .any(|r| r["name"] == "mock_rollback_cache"));
+        drop(state);
+    }
+
+    #[tokio::test]
+    async fn test_deploy_configuration_fail_fast() {
+        shared();
+        let config = |suffix: &str| Conf {
+            services: vec![
+                failing_service(&format!("mock_failing_{}", suffix)),
+                web_service(&format!("mock_working_{}", suffix)),
+            ],
+            ..Default::default()
+        };
+
+        // Stops at the first failure by default.
+        let (_directory, path) = write_config(config("fail_fast"));
+        let error = ServiceManager::deploy_configuration(&path)
+            .await
+            .unwrap_err();
+        let report = error.downcast_ref::<DeploymentReport>().unwrap();
+        assert_eq!(report.resources[1].status, DeploymentStatus::Skipped);
+
+        // The cost estimate is part of the report.
+        assert_eq!(report.cost.as_ref().unwrap().items.len(), 2);
+        assert!(report.to_string().contains("Estimated monthly cost"));
+
+        // Attempts every resource otherwise.
+        let (_directory, path) = write_config(config("continue"));
+        let options = DeploymentOptions {
+            fail_fast: false,
+            ..Default::default()
+        };
+        let error = ServiceManager::deploy_configuration_with_options(&path, options)
+            .await
+            .unwrap_err();
+        let report = error.downcast_ref::<DeploymentReport>().unwrap();
+        assert_eq!(report.resources[1].status, DeploymentStatus::Created);
+        assert!(report.resources[1]
+            .dashboard_url
+            .as_deref()
+            .unwrap()
+            .contains(report.resources[1].id.as_deref().unwrap()));
+    }
+}
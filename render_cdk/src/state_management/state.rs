@@ -11,7 +11,7 @@ use anyhow::{Context, Error, Ok, Result};
 use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE};
 use reqwest::{self};
 
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 // [render_cdk] modules.
 use crate::environment_management::prelude::*;
@@ -19,6 +19,9 @@ use crate::environment_management::prelude::*;
 // Predefined [CONSTANTS].
 const BASE_URL: &str = "https://api.render.com/v1";
 
+/// Replaces [BASE_URL] for the whole process, e.g to target a mock server.
+static BASE_URL_OVERRIDE: RwLock<Option<String>> = RwLock::new(None);
+
 ///////////////////
 ///// [State] ////
 ///////////////////
@@ -26,6 +29,7 @@ const BASE_URL: &str = "https://api.render.com/v1";
 pub struct State {
     pub CLIENT: Arc<reqwest::Client>,
     pub API_KEY: String,
+    pub BASE_URL: String,
 }
 
 ///////////////////
@@ -51,23 +55,38 @@ impl State {
     /// This method returns an instance of the applications current [State].
     pub async fn init() -> Self {
         let client = reqwest::Client::new();
-        let api_key = EnvironmentManager::retrieve_env_config().API_KEY;
+        let api_key = EnvironmentManager::api_key().unwrap_or_else(|err| panic!("{}", err));
 
         Self {
             CLIENT: client.into(),
             API_KEY: api_key,
+            BASE_URL: State::base_url(),
         }
     }
+
+    /// The Render API every request is sent to.
+    pub fn base_url() -> String {
+        BASE_URL_OVERRIDE
+            .read()
+            .unwrap()
+            .clone()
+            .unwrap_or_else(|| BASE_URL.to_owned())
+    }
+
+    /// Points every subsequent request at another API, `None` restores the default.
+    pub fn set_base_url(base_url: Option<&str>) {
+        *BASE_URL_OVERRIDE.write().unwrap() =
+            base_url.map(|url| url.trim_end_matches('/').to_owned());
+    }
 }
 
 impl Owner {
-    pub async fn list_authorized_users(
-        email: &str,
-        limit: &str,
-    ) -> Result<Vec<OwnerResponse>, Error> {
-        let client = State::init().await.CLIENT;
-        let api_key = format!("Bearer {}", State::init().await.API_KEY);
-        let api_url = format!("{}{}{}", BASE_URL, "/owners?limit=", limit);
+    /// Lists every owner (user account or team workspace) the [API_KEY] can access.
+    pub async fn list_owners(limit: &str) -> Result<Vec<OwnerResponse>, Error> {
+        let state = State::init().await;
+        let client = state.CLIENT;
+        let api_key = format!("Bearer {}", state.API_KEY);
+        let api_url = format!("{}{}{}", state.BASE_URL, "/owners?limit=", limit);
 
         let response = client
             .get(api_url)
@@ -78,17 +97,6 @@ impl Owner {
             .context("Error sending request.")?;
 
         if response.status().is_success() {
-            let authorized_users = response
-                .json::<Vec<OwnerResponse>>()
-                .await
-                .context("Error parsing response.")?;
-
-            // Filter for the user.
-            let filtered_owners: Vec<OwnerResponse> = authorized_users
-                .into_iter()
-                .filter(|result| result.owner.email == email)
-                .collect();
-
             /*
                 Expected <response>.
 
@@ -105,8 +113,12 @@ impl Owner {
                     }
                 ]
             */
+            let owners = response
+                .json::<Vec<OwnerResponse>>()
+                .await
+                .context("Error parsing response.")?;
 
-            Ok(filtered_owners)
+            Ok(owners)
         } else {
             Err(anyhow::anyhow!(
                 "Request failed with status: {}",
@@ -114,18 +126,45 @@ impl Owner {
             ))
         }
     }
+
+    /// Lists the owners whose email matches the provided [OWNER_CREDENTIALS].
+    pub async fn list_authorized_users(
+        email: &str,
+        limit: &str,
+    ) -> Result<Vec<OwnerResponse>, Error> {
+        let authorized_users = Owner::list_owners(limit).await?;
+
+        // Filter for the user.
+        let filtered_owners: Vec<OwnerResponse> = authorized_users
+            .into_iter()
+            .filter(|result| result.owner.email == email)
+            .collect();
+
+        Ok(filtered_owners)
+    }
 }
 
 #[cfg(test)]
 mod state_tests {
     use super::*;
+    use crate::testing::prelude::*;
 
     #[tokio::test]
     async fn test_list_authorized_users() {
-        let owner_credentials = EnvironmentManager::retrieve_env_config().OWNER_CREDENTIALS;
+        shared();
+        let owner_credentials = EnvironmentManager::owner_credentials().unwrap();
         let result = Owner::list_authorized_users(&owner_credentials, "100").await;
 
         // The result should be Ok().
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_list_owners() {
+        shared();
+        let result = Owner::list_owners("100").await;
+
+        // The result should be Ok().
+        assert!(result.is_ok());
+    }
 }
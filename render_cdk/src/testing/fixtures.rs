@@ -0,0 +1,283 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+// [JSON] parsing.
+use serde_json::{json, Value};
+
+// [render_cdk] modules.
+use crate::resource_management::models::prelude::*;
+
+// Predefined [CONSTANTS].
+pub const MOCK_API_KEY: &str = "rnd_mockApiKey0000000000";
+pub const MOCK_OWNER_EMAIL: &str = "owner@render-cdk.test";
+pub const MOCK_OWNER_ID: &str = "usr-mock00000000000000";
+pub const MOCK_TEAM_ID: &str = "tea-mock00000000000000";
+pub const MOCK_REGISTRY_CREDENTIAL_ID: &str = "rcr-mockdockerhub000000";
+pub const MOCK_DISK_ID: &str = "dsk-mockwhoamidata00000";
+
+/// The personal account, followed by a team sharing the same email.
+pub fn owners() -> Vec<Value> {
+    vec![
+        json!({
+            "id": MOCK_OWNER_ID,
+            "name": "render-cdk",
+            "email": MOCK_OWNER_EMAIL,
+            "twoFactorAuthEnabled": false,
+            "type": "user"
+        }),
+        json!({
+            "id": MOCK_TEAM_ID,
+            "name": "platform",
+            "email": MOCK_OWNER_EMAIL,
+            "twoFactorAuthEnabled": true,
+            "type": "team"
+        }),
+    ]
+}
+
+pub fn services() -> Vec<Value> {
+    vec![
+        service(
+            "srv-mockwhoami000000000",
+            "whoami",
+            "web_service",
+            "oregon",
+            "node",
+            "not_suspended",
+        ),
+        service(
+            "srv-mockimage0000000000",
+            "whoami-image",
+            "web_service",
+            "frankfurt",
+            "image",
+            "not_suspended",
+        ),
+        service(
+            "srv-mockstatic000000000",
+            "archived-docs",
+            "static_site",
+            "oregon",
+            "static",
+            "suspended",
+        ),
+    ]
+}
+
+pub fn postgres_instances() -> Vec<Value> {
+    vec![
+        postgres("dpg-mockfluentcomet0000", "fluentcomet", "not_suspended"),
+        postgres("dpg-mockdormantnova0000", "dormantnova", "suspended"),
+    ]
+}
+
+pub fn redis_instances() -> Vec<Value> {
+    vec![redis("red-mockfluentcomet0000", "fluentcomet")]
+}
+
+pub fn registry_credentials() -> Vec<Value> {
+    vec![json!({
+        "id": MOCK_REGISTRY_CREDENTIAL_ID,
+        "name": "dockerhub",
+        "registry": "DOCKER",
+        "username": "render-cdk",
+        "updatedAt": "2024-01-01T00:00:00.000000Z"
+    })]
+}
+
+/// A disk attached to `whoami`.
+pub fn disks() -> Vec<Value> {
+    vec![json!({
+        "id": MOCK_DISK_ID,
+        "name": "whoami-data",
+        "mountPath": "/var/lib/whoami",
+        "sizeGB": 10,
+        "serviceId": "srv-mockwhoami000000000",
+        "createdAt": "2024-01-01T00:00:00.000000Z",
+        "updatedAt": "2024-01-01T00:00:00.000000Z"
+    })]
+}
+
+/// Daily snapshots of the disks, most recent first.
+pub fn disk_snapshots() -> Vec<Value> {
+    vec![
+        json!({
+            "snapshotKey": "snp-mockwhoamidata0002",
+            "createdAt": "2024-01-02T00:00:00.000000Z"
+        }),
+        json!({
+            "snapshotKey": "snp-mockwhoamidata0001",
+            "createdAt": "2024-01-01T00:00:00.000000Z"
+        }),
+    ]
+}
+
+/// Logs of `whoami` and `fluentcomet` (Postgres), oldest first.
+pub fn logs() -> Vec<Value> {
+    vec![
+        log_entry(
+            "srv-mockwhoami000000000",
+            "2024-01-01T00:00:00Z",
+            "info",
+            "build",
+            "==> Build successful",
+        ),
+        log_entry(
+            "srv-mockwhoami000000000",
+            "2024-01-01T00:01:00Z",
+            "info",
+            "app",
+            "Listening on port 10000",
+        ),
+        log_entry(
+            "srv-mockwhoami000000000",
+            "2024-01-01T00:02:00Z",
+            "info",
+            "request",
+            "GET / 200",
+        ),
+        log_entry(
+            "srv-mockwhoami000000000",
+            "2024-01-01T00:03:00Z",
+            "error",
+            "app",
+            "Unhandled rejection: connection refused",
+        ),
+        log_entry(
+            "dpg-mockfluentcomet0000",
+            "2024-01-01T00:00:30Z",
+            "info",
+            "app",
+            "database system is ready to accept connections",
+        ),
+    ]
+}
+
+pub fn log_entry(
+    resource: &str,
+    timestamp: &str,
+    level: &str,
+    type_: &str,
+    message: &str,
+) -> Value {
+    json!({
+        "id": format!("log-{}-{}", resource, timestamp),
+        "timestamp": timestamp,
+        "message": message,
+        "labels": [
+            { "name": "resource", "value": resource },
+            { "name": "instance", "value": format!("{}-instance", resource) },
+            { "name": "level", "value": level },
+            { "name": "type", "value": type_ }
+        ]
+    })
+}
+
+pub fn service(
+    id: &str,
+    name: &str,
+    type_: &str,
+    region: &str,
+    env: &str,
+    suspended: &str,
+) -> Value {
+    json!({
+        "id": id,
+        "name": name,
+        "type": type_,
+        "ownerId": MOCK_OWNER_ID,
+        "repo": format!("https://github.com/lexara-prime-ai/{}", name),
+        "autoDeploy": "yes",
+        "branch": "main",
+        "suspended": suspended,
+        "suspenders": [],
+        "createdAt": "2024-01-01T00:00:00.000000Z",
+        "updatedAt": "2024-01-01T00:00:00.000000Z",
+        "dashboardUrl": format!("https://dashboard.render.com/web/{}", id),
+        "serviceDetails": {
+            "region": region,
+            "env": env,
+            "plan": "starter",
+            "numInstances": 1
+        }
+    })
+}
+
+pub fn postgres(id: &str, name: &str, suspended: &str) -> Value {
+    json!({
+        "id": id,
+        "name": name,
+        "databaseName": name,
+        "databaseUser": name,
+        "plan": "starter",
+        "region": "oregon",
+        "version": "16",
+        "status": if suspended == "suspended" { "suspended" } else { "available" },
+        "suspended": suspended,
+        "highAvailabilityEnabled": false,
+        "ipAllowList": [],
+        "readReplicas": [],
+        "owner": { "id": MOCK_OWNER_ID, "email": MOCK_OWNER_EMAIL },
+        "createdAt": "2024-01-01T00:00:00.000000Z",
+        "updatedAt": "2024-01-01T00:00:00.000000Z",
+        "dashboardUrl": format!("https://dashboard.render.com/d/{}", id)
+    })
+}
+
+pub fn redis(id: &str, name: &str) -> Value {
+    json!({
+        "id": id,
+        "name": name,
+        "plan": "starter",
+        "region": "oregon",
+        "status": "available",
+        "suspended": "not_suspended",
+        "maxmemoryPolicy": "allkeys_lru",
+        "ipAllowList": [],
+        "owner": { "id": MOCK_OWNER_ID, "email": MOCK_OWNER_EMAIL },
+        "createdAt": "2024-01-01T00:00:00.000000Z",
+        "updatedAt": "2024-01-01T00:00:00.000000Z",
+        "dashboardUrl": format!("https://dashboard.render.com/r/{}", id)
+    })
+}
+
+/// A minimal static site, see [./main.rs].
+pub fn static_site(name: &str) -> Template {
+    Template {
+        type_: ServiceType::StaticSite,
+        name: name.to_owned(),
+        repo: "https://github.com/lexara-prime-ai/SAMPLE_STATIC_SITE".to_owned(),
+        auto_deploy: Some(Toggle::Yes),
+        root_dir: Some("./public".to_owned()),
+        service_details: Some(ServiceDetails {
+            publish_path: Some("./".to_owned()),
+            pull_request_previews_enabled: Some(Toggle::Yes),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// A minimal Node.js web service, see [./main.rs].
+pub fn web_service(name: &str) -> Template {
+    Template {
+        type_: ServiceType::WebService,
+        name: name.to_owned(),
+        repo: "https://github.com/lexara-prime-ai/SAMPLE_WEB_SERVICE".to_owned(),
+        auto_deploy: Some(Toggle::Yes),
+        root_dir: Some("./".to_owned()),
+        service_details: Some(ServiceDetails {
+            region: Some(Region::Oregon),
+            plan: Some(ServicePlan::Starter),
+            runtime: Some(Runtime::Node),
+            num_instances: Some(1),
+            env_specific_details: Some(EnvSpecificDetails {
+                build_command: Some("yarn".to_owned()),
+                start_command: Some("npm start".to_owned()),
+                ..Default::default()
+            }),
+            pull_request_previews_enabled: Some(Toggle::Yes),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
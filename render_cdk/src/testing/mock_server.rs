@@ -0,0 +1,1412 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+// [JSON] parsing.
+use serde_json::{json, Map, Value};
+
+// HTTP.
+use axum::extract::{self, Path, Query, Request};
+use axum::http::{header::AUTHORIZATION, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+
+// [render_cdk] modules.
+use super::fixtures::*;
+use crate::environment_management::prelude::*;
+use crate::state_management::state::State;
+use crate::utils::overlay::merge;
+use crate::utils::random::GENERATE_RANDOM_STRING;
+
+/// In-memory resources served by the [MockServer].
+#[derive(Debug, Default, Clone)]
+pub struct MockState {
+    pub owners: Vec<Value>,
+    pub services: Vec<Value>,
+    pub postgres: Vec<Value>,
+    pub redis: Vec<Value>,
+    pub registry_credentials: Vec<Value>,
+    pub disks: Vec<Value>,
+    /// Postgres exports, keyed by instance id.
+    pub exports: HashMap<String, Vec<Value>>,
+    /// Disk snapshots, keyed by disk id.
+    pub snapshots: HashMap<String, Vec<Value>>,
+    /// Deploys, keyed by service id.
+    pub deploys: HashMap<String, Vec<Value>>,
+    /// Events i.e cron job runs, keyed by service id.
+    pub events: HashMap<String, Vec<Value>>,
+    /// Log entries of every resource, labelled with their resource id.
+    pub logs: Vec<Value>,
+    /// Authorized requests received, keyed by method and path i.e `GET /owners`.
+    pub requests: HashMap<String, usize>,
+}
+
+impl MockState {
+    /// The resources described in [super::fixtures].
+    pub fn seeded() -> Self {
+        Self {
+            owners: owners(),
+            services: services(),
+            postgres: postgres_instances(),
+            redis: redis_instances(),
+            registry_credentials: registry_credentials(),
+            disks: disks(),
+            exports: HashMap::new(),
+            snapshots: HashMap::from([(MOCK_DISK_ID.to_owned(), disk_snapshots())]),
+            deploys: HashMap::new(),
+            events: HashMap::new(),
+            logs: logs(),
+            requests: HashMap::new(),
+        }
+    }
+}
+
+/// An in-process emulation of the Render API.
+///
+/// Requests must be authorized with [MOCK_API_KEY], which [MockServer::install]
+/// configures along with the base url of the server.
+#[derive(Debug)]
+pub struct MockServer {
+    url: String,
+    state: Arc<Mutex<MockState>>,
+    shutdown: Option<tokio::sync::oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// Starts a server seeded with [MockState::seeded].
+    pub fn start() -> Self {
+        MockServer::start_with(MockState::seeded())
+    }
+
+    /// Starts a server on its own thread, so it outlives any single test runtime.
+    pub fn start_with(state: MockState) -> Self {
+        let listener =
+            std::net::TcpListener::bind("127.0.0.1:0").expect("Unable to BIND mock server.");
+        listener
+            .set_nonblocking(true)
+            .expect("Unable to configure mock server.");
+
+        let url = format!("http://{}/v1", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(state));
+        let api = MockApi {
+            state: state.clone(),
+            api_key: MOCK_API_KEY.to_owned(),
+            url: url.clone(),
+        };
+        let (shutdown, signal) = tokio::sync::oneshot::channel::<()>();
+
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("Unable to start mock server runtime.");
+
+            runtime.block_on(async move {
+                let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+                axum::serve(listener, router(api))
+                    .with_graceful_shutdown(async {
+                        signal.await.ok();
+                    })
+                    .await
+                    .unwrap();
+            });
+        });
+
+        Self {
+            url,
+            state,
+            shutdown: Some(shutdown),
+        }
+    }
+
+    /// Base url of the emulated API, i.e `http://127.0.0.1:<port>/v1`.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Points every request made by this process at the server.
+    pub fn install(&self) {
+        State::set_base_url(Some(&self.url));
+        EnvironmentManager::configure(
+            CredentialSources::new()
+                .value(Credential::ApiKey, MOCK_API_KEY)
+                .value(Credential::OwnerCredentials, MOCK_OWNER_EMAIL),
+        );
+    }
+
+    /// Authorized requests received by a route so far, i.e `hits("GET", "/owners")`.
+    pub fn hits(&self, method: &str, path: &str) -> usize {
+        let route = format!("{} {}", method, path);
+        self.state()
+            .requests
+            .get(&route)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Direct access to the in-memory resources, for seeding and assertions.
+    pub fn state(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            shutdown.send(()).ok();
+        }
+    }
+}
+
+static SHARED: OnceLock<MockServer> = OnceLock::new();
+
+/// A seeded server shared by every test in the process, installed on each call.
+///
+/// Tests run in parallel, so they should use unique resource names.
+pub fn shared() -> &'static MockServer {
+    let server = SHARED.get_or_init(MockServer::start);
+    server.install();
+    server
+}
+
+///////////////////
+///// [Routes] ////
+///////////////////
+#[derive(Debug, Clone)]
+struct MockApi {
+    state: Arc<Mutex<MockState>>,
+    api_key: String,
+    /// Base url, for links i.e export downloads.
+    url: String,
+}
+
+impl MockApi {
+    fn state(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap()
+    }
+}
+
+type Params = Query<HashMap<String, String>>;
+
+const SERVICE_FILTERS: &[(&str, &str)] = &[
+    ("name", "/name"),
+    ("type", "/type"),
+    ("region", "/serviceDetails/region"),
+    ("env", "/serviceDetails/env"),
+    ("suspended", "/suspended"),
+    ("ownerId", "/ownerId"),
+];
+
+const DISK_FILTERS: &[(&str, &str)] = &[("name", "/name"), ("serviceId", "/serviceId")];
+
+const DATASTORE_FILTERS: &[(&str, &str)] = &[
+    ("name", "/name"),
+    ("region", "/region"),
+    ("suspended", "/suspended"),
+    ("ownerId", "/owner/id"),
+];
+
+fn router(api: MockApi) -> Router {
+    let routes = Router::new()
+        .route("/owners", get(list_owners))
+        .route("/services", get(list_services).post(create_service))
+        .route(
+            "/services/{id}",
+            get(retrieve_service)
+                .patch(update_service)
+                .delete(delete_service),
+        )
+        .route("/services/{id}/events", get(list_events))
+        .route(
+            "/services/{id}/deploys",
+            get(list_deploys).post(trigger_deploy),
+        )
+        .route("/services/{id}/deploys/{deploy_id}", get(retrieve_deploy))
+        .route("/cron-jobs/{id}/runs", post(trigger_cron_job_run))
+        .route(
+            "/registrycredentials",
+            get(list_registry_credentials).post(create_registry_credential),
+        )
+        .route(
+            "/registrycredentials/{id}",
+            get(retrieve_registry_credential)
+                .patch(update_registry_credential)
+                .delete(delete_registry_credential),
+        )
+        .route("/logs", get(list_logs))
+        .route("/disks", get(list_disks).post(create_disk))
+        .route(
+            "/disks/{id}",
+            get(retrieve_disk).patch(update_disk).delete(delete_disk),
+        )
+        .route("/disks/{id}/snapshots", get(list_disk_snapshots))
+        .route("/disks/{id}/snapshots/restore", post(restore_disk_snapshot))
+        .route("/postgres", get(list_postgres).post(create_postgres))
+        .route(
+            "/postgres/{id}",
+            get(retrieve_postgres)
+                .patch(update_postgres)
+                .delete(delete_postgres),
+        )
+        .route("/postgres/{id}/suspend", post(suspend_postgres))
+        .route("/postgres/{id}/resume", post(resume_postgres))
+        .route("/postgres/{id}/restart", post(restart_postgres))
+        .route(
+            "/postgres/{id}/connection-info",
+            get(retrieve_postgres_connection_info),
+        )
+        .route(
+            "/postgres/{id}/export",
+            get(list_postgres_exports).post(create_postgres_export),
+        )
+        .route(
+            "/postgres/{id}/recovery",
+            get(retrieve_postgres_recovery).post(recover_postgres),
+        )
+        .route("/redis", get(list_redis).post(create_redis))
+        .route(
+            "/redis/{id}",
+            get(retrieve_redis).patch(update_redis).delete(delete_redis),
+        )
+        .route("/redis/{id}/suspend", post(suspend_redis))
+        .route("/redis/{id}/resume", post(resume_redis))
+        .route(
+            "/redis/{id}/connection-info",
+            get(retrieve_redis_connection_info),
+        )
+        .fallback(not_found)
+        .layer(middleware::from_fn_with_state(api.clone(), authorize))
+        .with_state(api.clone());
+
+    // Like pre-signed urls, export downloads aren't authorized with the API key.
+    let downloads = Router::new()
+        .route("/exports/{id}", get(download_postgres_export))
+        .with_state(api);
+
+    Router::new().nest("/v1", routes).merge(downloads)
+}
+
+async fn authorize(
+    extract::State(api): extract::State<MockApi>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let expected = format!("Bearer {}", api.api_key);
+
+    match request.headers().get(AUTHORIZATION) {
+        Some(value) if value == expected.as_str() => {
+            let route = format!("{} {}", request.method(), request.uri().path());
+            *api.state().requests.entry(route).or_default() += 1;
+            next.run(request).await
+        }
+        _ => error(StatusCode::UNAUTHORIZED, "unauthorized"),
+    }
+}
+
+async fn not_found() -> Response {
+    error(StatusCode::NOT_FOUND, "not found")
+}
+
+async fn list_owners(
+    extract::State(api): extract::State<MockApi>,
+    Query(params): Params,
+) -> Response {
+    list(
+        &api.state().owners,
+        "owner",
+        &[("name", "/name"), ("email", "/email")],
+        &params,
+    )
+}
+
+async fn list_services(
+    extract::State(api): extract::State<MockApi>,
+    Query(params): Params,
+) -> Response {
+    list(&api.state().services, "service", SERVICE_FILTERS, &params)
+}
+
+async fn retrieve_service(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    retrieve(&api.state().services, &id)
+}
+
+async fn delete_service(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    let mut state = api.state();
+    state.deploys.remove(&id);
+    state.disks.retain(|disk| disk["serviceId"] != id);
+    delete(&mut state.services, &id)
+}
+
+async fn update_service(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+    body: String,
+) -> Response {
+    let mut state = api.state();
+    let body = match parse_body(&body, &[], &state.owners) {
+        Ok(body) => body,
+        Err(response) => return *response,
+    };
+
+    match state
+        .services
+        .iter_mut()
+        .find(|service| service["id"] == id)
+    {
+        Some(service) => {
+            merge(service, Value::Object(body));
+            service["updatedAt"] = json!(now());
+            Json(service.clone()).into_response()
+        }
+        None => error(StatusCode::NOT_FOUND, "not found"),
+    }
+}
+
+async fn list_events(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+    Query(params): Params,
+) -> Response {
+    let state = api.state();
+    match state.events.get(&id) {
+        Some(events) => list(events, "event", &[("type", "/type")], &params),
+        None if find(&state.services, &id).is_some() => list(&[], "event", &[], &params),
+        None => error(StatusCode::NOT_FOUND, "service not found"),
+    }
+}
+
+/// Runs complete immediately, recording a `cron_job_run_ended` event.
+async fn trigger_cron_job_run(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    let mut state = api.state();
+    match find(&state.services, &id) {
+        Some(service) if service["type"] == "cron_job" => {}
+        _ => return error(StatusCode::NOT_FOUND, "cron job not found"),
+    }
+
+    let run = json!({
+        "id": new_id("crn"),
+        "status": "pending",
+        "startedAt": now()
+    });
+    let event = json!({
+        "id": new_id("evt"),
+        "timestamp": now(),
+        "serviceId": id,
+        "type": "cron_job_run_ended",
+        "details": { "cronJobRunId": run["id"], "status": "successful" }
+    });
+    state.events.entry(id).or_default().insert(0, event);
+
+    Json(run).into_response()
+}
+
+async fn create_service(extract::State(api): extract::State<MockApi>, body: String) -> Response {
+    let mut state = api.state();
+    let body = match parse_body(&body, &["type", "name", "ownerId"], &state.owners) {
+        Ok(body) => body,
+        Err(response) => return *response,
+    };
+
+    let id = new_id("srv");
+    let mut service = body.clone();
+    let details = service
+        .entry("serviceDetails")
+        .or_insert_with(|| json!({}))
+        .as_object_mut()
+        .unwrap();
+    details.entry("region").or_insert_with(|| json!("oregon"));
+    let env = details
+        .get("runtime")
+        .cloned()
+        .unwrap_or_else(|| json!("static"));
+    details.entry("env").or_insert(env);
+
+    // The disk is created along with the service, which only references it.
+    if let Some(disk) = details.get("disk").cloned() {
+        let disk = new_disk(&disk, &id);
+        details.insert(
+            "disk".to_owned(),
+            json!({ "id": disk["id"], "name": disk["name"] }),
+        );
+        state.disks.push(disk);
+    }
+
+    service.insert("id".to_owned(), json!(id));
+    service.insert("suspended".to_owned(), json!("not_suspended"));
+    service.insert("suspenders".to_owned(), json!([]));
+    service.insert("createdAt".to_owned(), json!(now()));
+    service.insert("updatedAt".to_owned(), json!(now()));
+    service.insert(
+        "dashboardUrl".to_owned(),
+        json!(format!("https://dashboard.render.com/web/{}", id)),
+    );
+
+    let deploy = new_deploy("new_commit");
+    let deploy_id = deploy["id"].clone();
+    state.deploys.insert(id.clone(), vec![deploy]);
+    state.services.push(Value::Object(service.clone()));
+
+    created(json!({ "service": service, "deployId": deploy_id }))
+}
+
+async fn list_deploys(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+    Query(params): Params,
+) -> Response {
+    let state = api.state();
+    match state.deploys.get(&id) {
+        Some(deploys) => list(deploys, "deploy", &[("status", "/status")], &params),
+        None if find(&state.services, &id).is_some() => list(&[], "deploy", &[], &params),
+        None => error(StatusCode::NOT_FOUND, "service not found"),
+    }
+}
+
+async fn trigger_deploy(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    let mut state = api.state();
+    if find(&state.services, &id).is_none() {
+        return error(StatusCode::NOT_FOUND, "service not found");
+    }
+
+    let deploy = new_deploy("api");
+    state
+        .deploys
+        .entry(id)
+        .or_default()
+        .insert(0, deploy.clone());
+
+    created(deploy)
+}
+
+async fn retrieve_deploy(
+    extract::State(api): extract::State<MockApi>,
+    Path((id, deploy_id)): Path<(String, String)>,
+) -> Response {
+    let state = api.state();
+    let deploys = state.deploys.get(&id).map(Vec::as_slice).unwrap_or(&[]);
+    retrieve(deploys, &deploy_id)
+}
+
+async fn list_postgres(
+    extract::State(api): extract::State<MockApi>,
+    Query(params): Params,
+) -> Response {
+    list(
+        &api.state().postgres,
+        "postgres",
+        DATASTORE_FILTERS,
+        &params,
+    )
+}
+
+async fn retrieve_postgres(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    retrieve(&api.state().postgres, &id)
+}
+
+async fn delete_postgres(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    delete(&mut api.state().postgres, &id)
+}
+
+/// Replicas keep their id when listed again, and are deleted when left out.
+async fn update_postgres(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+    body: String,
+) -> Response {
+    let mut state = api.state();
+    let mut body = match parse_body(&body, &[], &state.owners) {
+        Ok(body) => body,
+        Err(response) => return *response,
+    };
+
+    let Some(postgres) = state
+        .postgres
+        .iter_mut()
+        .find(|postgres| postgres["id"] == id)
+    else {
+        return error(StatusCode::NOT_FOUND, "not found");
+    };
+
+    if let Some(enabled) = body.remove("enableHighAvailability") {
+        postgres["highAvailabilityEnabled"] = enabled;
+    }
+    if let Some(Value::Array(requested)) = body.remove("readReplicas") {
+        let replicas: Vec<Value> = requested
+            .iter()
+            .map(|replica| {
+                let existing = postgres["readReplicas"]
+                    .as_array()
+                    .and_then(|replicas| {
+                        replicas
+                            .iter()
+                            .find(|existing| existing["name"] == replica["name"])
+                    })
+                    .map(|existing| existing["id"].clone());
+                json!({
+                    "id": existing.unwrap_or_else(|| json!(new_id("dpg"))),
+                    "name": replica["name"]
+                })
+            })
+            .collect();
+        postgres["readReplicas"] = json!(replicas);
+    }
+    // Lists are replaced rather than merged.
+    if let Some(ip_allow_list) = body.remove("ipAllowList") {
+        postgres["ipAllowList"] = ip_allow_list;
+    }
+
+    merge(postgres, Value::Object(body));
+    postgres["updatedAt"] = json!(now());
+    Json(postgres.clone()).into_response()
+}
+
+async fn suspend_postgres(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    set_status(&mut api.state().postgres, &id, "suspended", "suspended")
+}
+
+async fn resume_postgres(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    set_status(&mut api.state().postgres, &id, "not_suspended", "available")
+}
+
+async fn restart_postgres(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    match find(&api.state().postgres, &id) {
+        Some(postgres) if postgres["suspended"] == "suspended" => {
+            error(StatusCode::CONFLICT, "postgres instance is suspended")
+        }
+        Some(_) => StatusCode::ACCEPTED.into_response(),
+        None => error(StatusCode::NOT_FOUND, "not found"),
+    }
+}
+
+async fn retrieve_postgres_connection_info(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    let state = api.state();
+    let Some(postgres) = find(&state.postgres, &id) else {
+        return error(StatusCode::NOT_FOUND, "not found");
+    };
+
+    let user = postgres["databaseUser"].as_str().unwrap_or_default();
+    let database = postgres["databaseName"].as_str().unwrap_or_default();
+    let region = postgres["region"].as_str().unwrap_or_default();
+    let password = format!("mock-password-{}", id);
+    let external_host = format!("{}.{}-postgres.render.com", id, region);
+
+    Json(json!({
+        "password": password,
+        "internalConnectionString": format!("postgresql://{}:{}@{}/{}", user, password, id, database),
+        "externalConnectionString": format!("postgresql://{}:{}@{}/{}", user, password, external_host, database),
+        "psqlCommand": format!("PGPASSWORD={} psql -h {} {} {}", password, external_host, user, database)
+    }))
+    .into_response()
+}
+
+async fn list_postgres_exports(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    let state = api.state();
+    match find(&state.postgres, &id) {
+        Some(_) => Json(json!(state.exports.get(&id).cloned().unwrap_or_default())).into_response(),
+        None => error(StatusCode::NOT_FOUND, "not found"),
+    }
+}
+
+/// Exports complete immediately, downloadable from `/exports/{id}`.
+async fn create_postgres_export(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    let mut state = api.state();
+    if find(&state.postgres, &id).is_none() {
+        return error(StatusCode::NOT_FOUND, "not found");
+    }
+
+    let export_id = new_id("exp");
+    // Mounted next to `/v1`.
+    let url = format!("{}/exports/{}", api.url.trim_end_matches("/v1"), export_id);
+    let export = json!({ "id": export_id, "createdAt": now(), "url": url });
+    state
+        .exports
+        .entry(id)
+        .or_default()
+        .insert(0, export.clone());
+
+    (StatusCode::ACCEPTED, Json(export)).into_response()
+}
+
+async fn download_postgres_export(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    let state = api.state();
+    let exported = state
+        .exports
+        .values()
+        .flatten()
+        .any(|export| export["id"] == id);
+    if !exported {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    format!("-- PostgreSQL database dump\n-- Export {}\n", id)
+        .repeat(256)
+        .into_response()
+}
+
+/// Instances can be restored to any time since their creation.
+async fn retrieve_postgres_recovery(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    match find(&api.state().postgres, &id) {
+        Some(postgres) if postgres["plan"] == "free" => {
+            Json(json!({ "recoveryStatus": "NOT_AVAILABLE" })).into_response()
+        }
+        Some(postgres) => Json(json!({
+            "recoveryStatus": "AVAILABLE",
+            "startsAt": postgres["createdAt"]
+        }))
+        .into_response(),
+        None => error(StatusCode::NOT_FOUND, "not found"),
+    }
+}
+
+/// Creates a copy of the instance, named after `restoreName`.
+async fn recover_postgres(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+    body: String,
+) -> Response {
+    let mut state = api.state();
+    let body = match parse_body(&body, &["restoreTime"], &state.owners) {
+        Ok(body) => body,
+        Err(response) => return *response,
+    };
+
+    let Some(original) = find(&state.postgres, &id) else {
+        return error(StatusCode::NOT_FOUND, "not found");
+    };
+    let restore_time = body["restoreTime"]
+        .as_str()
+        .and_then(|time| chrono::DateTime::parse_from_rfc3339(time).ok());
+    let starts_at = original["createdAt"]
+        .as_str()
+        .and_then(|time| chrono::DateTime::parse_from_rfc3339(time).ok());
+    match (restore_time, starts_at) {
+        (Some(restore_time), Some(starts_at)) if restore_time >= starts_at => {}
+        _ => {
+            return error(
+                StatusCode::BAD_REQUEST,
+                "restoreTime is outside of the recovery window",
+            )
+        }
+    }
+
+    let restored_id = new_id("dpg");
+    let mut restored = original.clone();
+    restored["id"] = json!(restored_id);
+    restored["name"] = json!(string_or_random(&body, "restoreName"));
+    restored["status"] = json!("recovery_in_progress");
+    restored["readReplicas"] = json!([]);
+    restored["createdAt"] = json!(now());
+    restored["updatedAt"] = json!(now());
+    restored["dashboardUrl"] = json!(format!("https://dashboard.render.com/d/{}", restored_id));
+    state.postgres.push(restored.clone());
+
+    created(restored)
+}
+
+async fn create_postgres(extract::State(api): extract::State<MockApi>, body: String) -> Response {
+    let mut state = api.state();
+    let body = match parse_body(&body, &["ownerId", "plan", "version"], &state.owners) {
+        Ok(body) => body,
+        Err(response) => return *response,
+    };
+
+    let id = new_id("dpg");
+    let name = string_or_random(&body, "name");
+    let postgres = json!({
+        "id": id,
+        "name": name,
+        "databaseName": string_or_random(&body, "databaseName"),
+        "databaseUser": string_or_random(&body, "databaseUser"),
+        "plan": body["plan"],
+        "region": body.get("region").cloned().unwrap_or_else(|| json!("oregon")),
+        "version": body["version"],
+        "status": "creating",
+        "suspended": "not_suspended",
+        "highAvailabilityEnabled": body.get("enableHighAvailability").cloned().unwrap_or(json!(false)),
+        "ipAllowList": body.get("ipAllowList").cloned().unwrap_or_else(|| json!([])),
+        "readReplicas": [],
+        "owner": owner_summary(&state.owners, &body),
+        "createdAt": now(),
+        "updatedAt": now(),
+        "dashboardUrl": format!("https://dashboard.render.com/d/{}", id)
+    });
+    state.postgres.push(postgres.clone());
+
+    created(postgres)
+}
+
+async fn list_redis(
+    extract::State(api): extract::State<MockApi>,
+    Query(params): Params,
+) -> Response {
+    list(&api.state().redis, "redis", DATASTORE_FILTERS, &params)
+}
+
+async fn retrieve_redis(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    retrieve(&api.state().redis, &id)
+}
+
+async fn delete_redis(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    delete(&mut api.state().redis, &id)
+}
+
+async fn update_redis(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+    body: String,
+) -> Response {
+    let mut state = api.state();
+    let mut body = match parse_body(&body, &[], &state.owners) {
+        Ok(body) => body,
+        Err(response) => return *response,
+    };
+
+    match state.redis.iter_mut().find(|redis| redis["id"] == id) {
+        Some(redis) => {
+            // Lists are replaced rather than merged.
+            if let Some(ip_allow_list) = body.remove("ipAllowList") {
+                redis["ipAllowList"] = ip_allow_list;
+            }
+            merge(redis, Value::Object(body));
+            redis["updatedAt"] = json!(now());
+            Json(redis.clone()).into_response()
+        }
+        None => error(StatusCode::NOT_FOUND, "not found"),
+    }
+}
+
+async fn suspend_redis(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    set_status(&mut api.state().redis, &id, "suspended", "suspended")
+}
+
+async fn resume_redis(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    set_status(&mut api.state().redis, &id, "not_suspended", "available")
+}
+
+async fn retrieve_redis_connection_info(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    let state = api.state();
+    let Some(redis) = find(&state.redis, &id) else {
+        return error(StatusCode::NOT_FOUND, "not found");
+    };
+
+    let region = redis["region"].as_str().unwrap_or_default();
+    let password = format!("mock-password-{}", id);
+    let external_host = format!("{}.{}-keyvalue.render.com", id, region);
+
+    Json(json!({
+        "internalConnectionString": format!("redis://{}:6379", id),
+        "externalConnectionString": format!("rediss://red:{}@{}:6379", password, external_host),
+        "redisCLICommand": format!("redis-cli --user red --pass {} -h {} -p 6379 --tls", password, external_host)
+    }))
+    .into_response()
+}
+
+async fn create_redis(extract::State(api): extract::State<MockApi>, body: String) -> Response {
+    let mut state = api.state();
+    let body = match parse_body(&body, &["ownerId", "plan"], &state.owners) {
+        Ok(body) => body,
+        Err(response) => return *response,
+    };
+
+    let id = new_id("red");
+    let redis = json!({
+        "id": id,
+        "name": string_or_random(&body, "name"),
+        "plan": body["plan"],
+        "region": body.get("region").cloned().unwrap_or_else(|| json!("oregon")),
+        "status": "creating",
+        "suspended": "not_suspended",
+        "maxmemoryPolicy": body.get("maxmemoryPolicy").cloned().unwrap_or_else(|| json!("allkeys_lru")),
+        "ipAllowList": body.get("ipAllowList").cloned().unwrap_or_else(|| json!([])),
+        "owner": owner_summary(&state.owners, &body),
+        "createdAt": now(),
+        "updatedAt": now(),
+        "dashboardUrl": format!("https://dashboard.render.com/r/{}", id)
+    });
+    state.redis.push(redis.clone());
+
+    created(redis)
+}
+
+/// Filters the entries of `resource` like the Render API, newest first unless `direction=forward`.
+async fn list_logs(
+    extract::State(api): extract::State<MockApi>,
+    Query(params): Params,
+) -> Response {
+    let (Some(_), Some(resources)) = (params.get("ownerId"), params.get("resource")) else {
+        return error(StatusCode::BAD_REQUEST, "ownerId and resource are required");
+    };
+    let limit = params
+        .get("limit")
+        .and_then(|limit| limit.parse::<usize>().ok())
+        .unwrap_or(20);
+    let time = |param: &str| {
+        params
+            .get(param)
+            .and_then(|time| chrono::DateTime::parse_from_rfc3339(time).ok())
+    };
+    let (start_time, end_time) = (time("startTime"), time("endTime"));
+    let label = |entry: &Value, name: &str| {
+        entry["labels"]
+            .as_array()
+            .and_then(|labels| labels.iter().find(|label| label["name"] == name))
+            .and_then(|label| label["value"].as_str())
+            .map(str::to_owned)
+    };
+
+    let state = api.state();
+    let mut entries: Vec<(chrono::DateTime<chrono::FixedOffset>, &Value)> = state
+        .logs
+        .iter()
+        .filter_map(|entry| {
+            let timestamp = entry["timestamp"]
+                .as_str()
+                .and_then(|time| chrono::DateTime::parse_from_rfc3339(time).ok())?;
+            Some((timestamp, entry))
+        })
+        .filter(|(timestamp, entry)| {
+            let labels_match = [
+                ("resource", "resource"),
+                ("level", "level"),
+                ("type", "type"),
+                ("instance", "instance"),
+            ]
+            .iter()
+            .all(|(param, name)| match params.get(*param) {
+                Some(expected) => {
+                    let actual = label(entry, name);
+                    expected
+                        .split(',')
+                        .any(|value| Some(value) == actual.as_deref())
+                }
+                None => true,
+            });
+            let text_matches = match params.get("text") {
+                Some(text) => {
+                    let message = entry["message"].as_str().unwrap_or_default();
+                    text.split(',').any(|text| message.contains(text))
+                }
+                None => true,
+            };
+
+            labels_match
+                && text_matches
+                && start_time.is_none_or(|start_time| *timestamp >= start_time)
+                && end_time.is_none_or(|end_time| *timestamp <= end_time)
+        })
+        .collect();
+
+    entries.sort_by_key(|(timestamp, _)| *timestamp);
+    let forward = params.get("direction").map(String::as_str) == Some("forward");
+    if !forward {
+        entries.reverse();
+    }
+
+    let has_more = entries.len() > limit;
+    entries.truncate(limit);
+    let next = entries
+        .last()
+        .filter(|_| has_more)
+        .map(|(_, entry)| entry["timestamp"].clone())
+        .unwrap_or(Value::Null);
+    let (next_start_time, next_end_time) = match forward {
+        true => (next, Value::Null),
+        false => (Value::Null, next),
+    };
+
+    Json(json!({
+        "hasMore": has_more,
+        "nextStartTime": next_start_time,
+        "nextEndTime": next_end_time,
+        "logs": entries.into_iter().map(|(_, entry)| entry.clone()).collect::<Vec<Value>>()
+    }))
+    .into_response()
+}
+
+async fn list_disks(
+    extract::State(api): extract::State<MockApi>,
+    Query(params): Params,
+) -> Response {
+    list(&api.state().disks, "disk", DISK_FILTERS, &params)
+}
+
+async fn retrieve_disk(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    retrieve(&api.state().disks, &id)
+}
+
+/// A service has at most one disk.
+async fn create_disk(extract::State(api): extract::State<MockApi>, body: String) -> Response {
+    let mut state = api.state();
+    let body = match parse_body(
+        &body,
+        &["name", "mountPath", "sizeGB", "serviceId"],
+        &state.owners,
+    ) {
+        Ok(body) => body,
+        Err(response) => return *response,
+    };
+
+    let service_id = body["serviceId"].as_str().unwrap_or_default();
+    if find(&state.services, service_id).is_none() {
+        return error(StatusCode::BAD_REQUEST, "invalid serviceId");
+    }
+    if state
+        .disks
+        .iter()
+        .any(|disk| disk["serviceId"] == service_id)
+    {
+        return error(StatusCode::CONFLICT, "service already has a disk");
+    }
+
+    let disk = new_disk(&Value::Object(body.clone()), service_id);
+    state.disks.push(disk.clone());
+
+    created(disk)
+}
+
+/// Disks can grow but not shrink.
+async fn update_disk(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+    body: String,
+) -> Response {
+    let mut state = api.state();
+    let body = match parse_body(&body, &[], &state.owners) {
+        Ok(body) => body,
+        Err(response) => return *response,
+    };
+
+    match state.disks.iter_mut().find(|disk| disk["id"] == id) {
+        Some(disk) => {
+            let size = body.get("sizeGB").and_then(Value::as_u64);
+            if size.is_some_and(|size| Some(size) < disk["sizeGB"].as_u64()) {
+                return error(StatusCode::BAD_REQUEST, "disks can't shrink");
+            }
+            merge(disk, Value::Object(body));
+            disk["updatedAt"] = json!(now());
+            Json(disk.clone()).into_response()
+        }
+        None => error(StatusCode::NOT_FOUND, "not found"),
+    }
+}
+
+async fn delete_disk(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    let mut state = api.state();
+    state.snapshots.remove(&id);
+    delete(&mut state.disks, &id)
+}
+
+async fn list_disk_snapshots(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    let state = api.state();
+    match find(&state.disks, &id) {
+        Some(_) => {
+            Json(json!(state.snapshots.get(&id).cloned().unwrap_or_default())).into_response()
+        }
+        None => error(StatusCode::NOT_FOUND, "not found"),
+    }
+}
+
+async fn restore_disk_snapshot(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+    body: String,
+) -> Response {
+    let state = api.state();
+    let body = match parse_body(&body, &["snapshotKey"], &state.owners) {
+        Ok(body) => body,
+        Err(response) => return *response,
+    };
+
+    let restored = state
+        .snapshots
+        .get(&id)
+        .into_iter()
+        .flatten()
+        .any(|snapshot| snapshot["snapshotKey"] == body["snapshotKey"]);
+    match find(&state.disks, &id) {
+        Some(disk) if restored => Json(disk.clone()).into_response(),
+        Some(_) => error(StatusCode::NOT_FOUND, "snapshot not found"),
+        None => error(StatusCode::NOT_FOUND, "not found"),
+    }
+}
+
+/// Unlike other resources, registry credentials are listed without a cursor.
+async fn list_registry_credentials(
+    extract::State(api): extract::State<MockApi>,
+    Query(params): Params,
+) -> Response {
+    let limit = params
+        .get("limit")
+        .and_then(|limit| limit.parse::<usize>().ok())
+        .unwrap_or(20);
+
+    let credentials: Vec<Value> = api
+        .state()
+        .registry_credentials
+        .iter()
+        .filter(|credential| {
+            params
+                .get("name")
+                .is_none_or(|name| name.split(',').any(|name| credential["name"] == name))
+        })
+        .take(limit)
+        .cloned()
+        .collect();
+
+    Json(Value::Array(credentials)).into_response()
+}
+
+async fn retrieve_registry_credential(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    retrieve(&api.state().registry_credentials, &id)
+}
+
+async fn create_registry_credential(
+    extract::State(api): extract::State<MockApi>,
+    body: String,
+) -> Response {
+    let mut state = api.state();
+    let body = match parse_body(
+        &body,
+        &["registry", "name", "username", "authToken", "ownerId"],
+        &state.owners,
+    ) {
+        Ok(body) => body,
+        Err(response) => return *response,
+    };
+
+    // The token is write only.
+    let credential = json!({
+        "id": new_id("rcr"),
+        "name": body["name"],
+        "registry": body["registry"],
+        "username": body["username"],
+        "updatedAt": now()
+    });
+    state.registry_credentials.push(credential.clone());
+
+    created(credential)
+}
+
+async fn update_registry_credential(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+    body: String,
+) -> Response {
+    let mut state = api.state();
+    let body = match parse_body(
+        &body,
+        &["registry", "name", "username", "authToken"],
+        &state.owners,
+    ) {
+        Ok(body) => body,
+        Err(response) => return *response,
+    };
+
+    match state
+        .registry_credentials
+        .iter_mut()
+        .find(|credential| credential["id"] == id)
+    {
+        Some(credential) => {
+            for field in ["name", "registry", "username"] {
+                credential[field] = body[field].clone();
+            }
+            credential["updatedAt"] = json!(now());
+            Json(credential.clone()).into_response()
+        }
+        None => error(StatusCode::NOT_FOUND, "not found"),
+    }
+}
+
+async fn delete_registry_credential(
+    extract::State(api): extract::State<MockApi>,
+    Path(id): Path<String>,
+) -> Response {
+    delete(&mut api.state().registry_credentials, &id)
+}
+
+////////////////////
+///// [Helpers] ////
+////////////////////
+fn error(status: StatusCode, message: &str) -> Response {
+    (
+        status,
+        Json(json!({ "id": status.as_u16().to_string(), "message": message })),
+    )
+        .into_response()
+}
+
+fn created(body: Value) -> Response {
+    (StatusCode::CREATED, Json(body)).into_response()
+}
+
+fn now() -> String {
+    chrono::Utc::now().to_rfc3339()
+}
+
+fn new_id(prefix: &str) -> String {
+    format!("{}-{}", prefix, GENERATE_RANDOM_STRING(20).to_lowercase())
+}
+
+fn new_deploy(trigger: &str) -> Value {
+    json!({
+        "id": new_id("dep"),
+        "status": "created",
+        "trigger": trigger,
+        "createdAt": now(),
+        "updatedAt": now()
+    })
+}
+
+fn new_disk(disk: &Value, service_id: &str) -> Value {
+    json!({
+        "id": new_id("dsk"),
+        "name": disk["name"],
+        "mountPath": disk["mountPath"],
+        "sizeGB": disk["sizeGB"],
+        "serviceId": service_id,
+        "createdAt": now(),
+        "updatedAt": now()
+    })
+}
+
+/// Suspends or resumes a datastore.
+fn set_status(resources: &mut [Value], id: &str, suspended: &str, status: &str) -> Response {
+    match resources.iter_mut().find(|resource| resource["id"] == id) {
+        Some(resource) => {
+            resource["suspended"] = json!(suspended);
+            resource["status"] = json!(status);
+            resource["updatedAt"] = json!(now());
+            StatusCode::ACCEPTED.into_response()
+        }
+        None => error(StatusCode::NOT_FOUND, "not found"),
+    }
+}
+
+fn string_or_random(body: &Map<String, Value>, field: &str) -> String {
+    body.get(field)
+        .and_then(Value::as_str)
+        .filter(|value| !value.is_empty())
+        .map(str::to_owned)
+        .unwrap_or_else(|| GENERATE_RANDOM_STRING(12).to_lowercase())
+}
+
+fn owner_summary(owners: &[Value], body: &Map<String, Value>) -> Value {
+    let owner = find(owners, body["ownerId"].as_str().unwrap_or_default());
+    json!({
+        "id": body["ownerId"],
+        "email": owner.map(|owner| owner["email"].clone()).unwrap_or(Value::Null)
+    })
+}
+
+/// Parses a JSON object, rejecting missing fields and unknown owners the way Render does.
+fn parse_body(
+    body: &str,
+    required: &[&str],
+    owners: &[Value],
+) -> Result<Map<String, Value>, Box<Response>> {
+    let body: Map<String, Value> = serde_json::from_str(body).map_err(|err| {
+        Box::new(error(
+            StatusCode::BAD_REQUEST,
+            &format!("invalid JSON: {}", err),
+        ))
+    })?;
+
+    for field in required {
+        let provided = match body.get(*field) {
+            Some(Value::String(value)) => !value.is_empty(),
+            Some(Value::Null) | None => false,
+            Some(_) => true,
+        };
+
+        if !provided {
+            return Err(Box::new(error(
+                StatusCode::BAD_REQUEST,
+                &format!("{} must be provided", field),
+            )));
+        }
+    }
+
+    if let Some(owner_id) = body.get("ownerId").and_then(Value::as_str) {
+        if find(owners, owner_id).is_none() {
+            return Err(Box::new(error(StatusCode::BAD_REQUEST, "invalid ownerId")));
+        }
+    }
+
+    Ok(body)
+}
+
+fn find<'a>(resources: &'a [Value], id: &str) -> Option<&'a Value> {
+    resources.iter().find(|resource| resource["id"] == id)
+}
+
+/// Applies the query filters (comma separated values match any) and `limit`,
+/// wrapping every resource along with its cursor.
+fn list(
+    resources: &[Value],
+    key: &str,
+    filters: &[(&str, &str)],
+    params: &HashMap<String, String>,
+) -> Response {
+    let limit = params
+        .get("limit")
+        .and_then(|limit| limit.parse::<usize>().ok())
+        .unwrap_or(20);
+
+    let page: Vec<Value> = resources
+        .iter()
+        .filter(|resource| {
+            filters
+                .iter()
+                .all(|(param, pointer)| match params.get(*param) {
+                    Some(expected) => {
+                        let actual = resource.pointer(pointer).and_then(Value::as_str);
+                        expected.split(',').any(|value| Some(value) == actual)
+                    }
+                    None => true,
+                })
+        })
+        .take(limit)
+        .map(|resource| json!({ key: resource, "cursor": resource["id"] }))
+        .collect();
+
+    Json(Value::Array(page)).into_response()
+}
+
+fn retrieve(resources: &[Value], id: &str) -> Response {
+    match find(resources, id) {
+        Some(resource) => Json(resource.clone()).into_response(),
+        None => error(StatusCode::NOT_FOUND, "not found"),
+    }
+}
+
+fn delete(resources: &mut Vec<Value>, id: &str) -> Response {
+    match resources.iter().position(|resource| resource["id"] == id) {
+        Some(index) => {
+            resources.remove(index);
+            StatusCode::NO_CONTENT.into_response()
+        }
+        None => error(StatusCode::NOT_FOUND, "not found"),
+    }
+}
+
+#[cfg(test)]
+mod mock_server_tests {
+    use super::*;
+    use crate::resource_management::models::enums::ServiceType;
+    use crate::resource_management::prelude::*;
+
+    #[tokio::test]
+    async fn test_create_find_and_delete_service() {
+        shared();
+
+        let created = ServiceManager::create_service(web_service("mock_created_web")).await;
+        assert!(created.is_ok());
+
+        let found = ServiceManager::find_service_by_name_and_type(
+            "mock_created_web",
+            ServiceType::WebService,
+        )
+        .await
+        .unwrap();
+        assert_eq!(found[0]["service"]["serviceDetails"]["env"], "node");
+
+        let deleted =
+            ServiceManager::delete_service("mock_created_web", ServiceType::WebService).await;
+        assert!(deleted.is_ok());
+
+        let found = ServiceManager::find_service_by_name_and_type(
+            "mock_created_web",
+            ServiceType::WebService,
+        )
+        .await
+        .unwrap();
+        assert!(found.as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_unknown_api_key() {
+        let server = shared();
+
+        let response = reqwest::Client::new()
+            .get(format!("{}/services", server.url()))
+            .header("Authorization", "Bearer rnd_invalid")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), 401);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod fixtures;
+pub mod mock_server;
+pub mod prelude;
+pub mod recorder;
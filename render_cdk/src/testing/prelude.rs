@@ -0,0 +1,3 @@
+pub use crate::testing::fixtures::*;
+pub use crate::testing::mock_server::*;
+pub use crate::testing::recorder::*;
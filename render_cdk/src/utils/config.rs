@@ -1,74 +1,90 @@
 #![allow(missing_docs)]
 #![allow(unused)]
 // [JSON] parsing.
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 // Idiomatic [ERROR] handling.
-use anyhow::Error;
+use anyhow::{Context, Error};
 
 // Randomization.
 use rand::distributions::Alphanumeric;
 use rand::{thread_rng, Rng};
 
 // File parsing.
+use std::collections::BTreeMap;
 use std::fs;
+use std::path::Path;
 use toml;
 
 // [render_cdk] modules.
-use crate::resource_management::models::caching::{CacheConf, RedisCidrAllowList};
-use crate::resource_management::models::storage::{DatabaseConf, PostgresCidrAllowList};
+use crate::resource_management::models::caching::CacheConf;
+use crate::resource_management::models::storage::DatabaseConf;
+use crate::resource_management::models::template::Template;
+use crate::utils::format::ConfigFormat;
+use crate::utils::naming::NamingPolicy;
+use crate::utils::{interpolation, overlay};
 
 // [DEBUG] utils.
 use crate::logger::prelude::*;
 use crate::utils::random::*;
 use crate::utils::stringify::Stringify;
+use crate::utils::validation::{Validate, ValidationErrors};
 use crate::LOGGER;
 use colored::Colorize;
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Default, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct Conf {
+    /// A managed Postgres instance.
     pub database: Option<DatabaseConf>,
+    /// A managed Redis instance.
     pub redis: Option<CacheConf>,
+    /// Services deployed along with the datastores.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub services: Vec<Template>,
+    /// How blank names are generated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub naming: Option<NamingPolicy>,
 }
 
 impl Conf {
     fn populate_blank_values(config: &mut Conf) {
+        // Blank names follow the [naming] policy, or are random when there is none.
+        let naming = config.naming.clone();
+        let name = |kind: &str| match &naming {
+            Some(policy) => policy.name(kind),
+            None => GENERATE_UNIQUE_NAME(),
+        };
+        let identifier = |kind: &str| match &naming {
+            Some(policy) => policy.identifier(kind),
+            None => GENERATE_UNIQUE_NAME(),
+        };
+
         // Validate [postgres] config.
         if let Some(database) = config.database.as_mut() {
             if database.databaseName.as_deref() == Some("") {
-                database.databaseName = Some(GENERATE_UNIQUE_NAME());
+                database.databaseName = Some(identifier("db"));
             }
 
             if database.databaseUser.as_deref() == Some("") {
-                database.databaseUser = Some(GENERATE_UNIQUE_NAME());
+                database.databaseUser = Some(identifier("user"));
             }
 
             if database.name.as_deref() == Some("") {
-                database.name = Some(GENERATE_UNIQUE_NAME());
+                database.name = Some(name("postgres"));
             }
 
-            // Provide <default> CIDR block.
-            if database.cidrBlocks.is_empty() {
-                database.cidrBlocks.push(PostgresCidrAllowList {
-                    cidrBlock: "0.0.0.0/0".to_string(),
-                    description: "Everywhere".to_string(),
-                });
-            }
+            // An empty [cidrBlocks] list blocks all external access.
         }
 
         // Validate [redis] config.
         if let Some(redis) = config.redis.as_mut() {
             if redis.name.as_deref() == Some("") {
-                redis.name = Some(GENERATE_UNIQUE_NAME());
+                redis.name = Some(name("redis"));
             }
 
-            // Provide <default> CIDR block.
-            if redis.cidrBlocks.is_empty() {
-                redis.cidrBlocks.push(RedisCidrAllowList {
-                    cidrBlock: "0.0.0.0/0".to_string(),
-                    description: "Everywhere".to_string(),
-                });
-            }
+            // An empty [cidrBlocks] list blocks all external access.
 
             if redis.plan.is_empty() {
                 redis.plan = "starter".to_owned();
@@ -77,15 +93,88 @@ impl Conf {
     }
 
     pub fn read_configuration_file(config_path: &str) -> Result<Self, Error> {
-        let contents = fs::read_to_string(config_path)
-            .unwrap_or_else(|_| panic!("Unable to READ configuration: {:?}", config_path));
+        Self::read_configuration_files(&[config_path])
+    }
+
+    /// Reads a base configuration, followed by `{name}.{environment}.conf` next to it,
+    /// i.e `./samples/sample.conf` and `./samples/sample.production.conf`.
+    pub fn read_environment(config_path: &str, environment: &str) -> Result<Self, Error> {
+        let overlay_path = Self::overlay_path(config_path, environment);
+
+        if !Path::new(&overlay_path).exists() {
+            return Err(anyhow::anyhow!(
+                "Unable to find [{}] overlay: {:?}",
+                environment,
+                overlay_path
+            ));
+        }
+
+        Self::read_configuration_files(&[config_path, &overlay_path])
+    }
 
-        // Parse config. file.
-        let mut config: Conf = toml::from_str(&contents)
-            .unwrap_or_else(|_| panic!("Unable to PARSE configuration: {:?}", config_path));
+    pub fn overlay_path(config_path: &str, environment: &str) -> String {
+        let path = Path::new(config_path);
+        let stem = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default();
+        let file_name = match path.extension().and_then(|e| e.to_str()) {
+            Some(extension) => format!("{}.{}.{}", stem, environment, extension),
+            None => format!("{}.{}", stem, environment),
+        };
+
+        path.with_file_name(file_name)
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Reads a base configuration followed by overlays, merged in order, see [overlay::merge].
+    pub fn read_configuration_files(config_paths: &[&str]) -> Result<Self, Error> {
+        let mut sources = vec![];
+        for config_path in config_paths {
+            let contents = fs::read_to_string(config_path)
+                .with_context(|| format!("Unable to READ configuration: {:?}", config_path))?;
+            sources.push((config_path.to_string(), contents));
+        }
+
+        // Substitute ${VAR}s, overlays can use (and override) the base [variables].
+        let formats: Vec<ConfigFormat> = sources
+            .iter()
+            .map(|(config_path, contents)| ConfigFormat::detect(config_path, contents))
+            .collect();
+
+        let mut variables = BTreeMap::new();
+        for ((config_path, contents), format) in sources.iter().zip(&formats) {
+            variables.extend(
+                interpolation::extract_variables(contents, *format)
+                    .with_context(|| format!("Unable to PARSE configuration: {:?}", config_path))?,
+            );
+        }
+        for (config_path, contents) in sources.iter_mut() {
+            *contents = interpolation::interpolate(contents, &variables, config_path)?;
+        }
+
+        // Parse config. file(s).
+        let mut config: Conf = match (sources.as_slice(), formats.as_slice()) {
+            ([(config_path, contents)], [format]) => format
+                .parse(contents)
+                .with_context(|| format!("Unable to PARSE configuration: {:?}", config_path))?,
+            _ => {
+                let mut merged = Value::Object(Default::default());
+                for ((config_path, contents), format) in sources.iter().zip(&formats) {
+                    let value: Value = format.parse(contents).with_context(|| {
+                        format!("Unable to PARSE configuration: {:?}", config_path)
+                    })?;
+                    overlay::merge(&mut merged, value);
+                }
+
+                serde_json::from_value(merged)
+                    .with_context(|| format!("Unable to PARSE configuration: {:?}", config_paths))?
+            }
+        };
 
         // Validate config. file.
-        if config.database.is_none() && config.redis.is_none() {
+        if config.database.is_none() && config.redis.is_none() && config.services.is_empty() {
             LOGGER!(
                 "\nFound empty configuration file -> ",
                 &config.stringify(),
@@ -94,6 +183,16 @@ impl Conf {
             return Err(anyhow::anyhow!("Found empty configuration file!"));
         }
 
+        let errors = config.validate().locate_in(&sources);
+        if !errors.is_empty() {
+            LOGGER!(
+                "\nFound invalid configuration file -> ",
+                errors.to_string(),
+                LogLevel::CRITICAL
+            );
+            return Err(errors.into());
+        }
+
         // Populate any <blank>/"" fields.
         Self::populate_blank_values(&mut config);
 
@@ -104,16 +203,31 @@ impl Conf {
             LogLevel::SUCCESS
         );
 
-        Ok(Self {
-            database: config.database,
-            redis: config.redis,
-        })
+        Ok(config)
+    }
+
+    /// The resolved configuration, i.e after merging overlays, for review.
+    pub fn to_toml(&self) -> Result<String, Error> {
+        self.to_format(ConfigFormat::Toml)
+    }
+
+    pub fn to_format(&self, format: ConfigFormat) -> Result<String, Error> {
+        format.serialize(self)
+    }
+
+    /// Writes the configuration, in the format matching the file extension (TOML otherwise).
+    pub fn write_configuration_file(&self, config_path: &str) -> Result<(), Error> {
+        let format = ConfigFormat::from_extension(config_path).unwrap_or(ConfigFormat::Toml);
+
+        fs::write(config_path, self.to_format(format)?)
+            .with_context(|| format!("Unable to WRITE configuration: {:?}", config_path))
     }
 }
 
 #[cfg(test)]
 mod config_test {
     use super::*;
+    use crate::resource_management::models::enums::Region;
 
     // Constants.
     const CONFIG_PATH: &str = "./samples/sample.conf";
@@ -124,14 +238,14 @@ mod config_test {
     #[test]
     fn test_read_configuration_file() {
         // Validate that the result is Ok().
-        let config = Conf::read_configuration_file(&CONFIG_PATH);
+        let config = Conf::read_configuration_file(CONFIG_PATH);
         assert!(config.is_ok());
     }
 
     #[test]
     fn test_generate_random_string() {
         // Validate that the output is NOT empty.
-        let config = Conf::read_configuration_file(&CONFIG_PATH).unwrap();
+        let config = Conf::read_configuration_file(CONFIG_PATH).unwrap();
         let result = GENERATE_RANDOM_STRING(10);
         assert!(!result.is_empty());
     }
@@ -139,16 +253,181 @@ mod config_test {
     #[test]
     fn test_generate_unique_name() {
         // Validate that the output is NOT empty.
-        let config = Conf::read_configuration_file(&CONFIG_PATH).unwrap();
+        let config = Conf::read_configuration_file(CONFIG_PATH).unwrap();
         let result = GENERATE_UNIQUE_NAME();
         assert!(!result.is_empty());
     }
 
     #[test]
     fn test_conf_to_json_string() {
-        let config = Conf::read_configuration_file(&CONFIG_PATH).unwrap();
+        let config = Conf::read_configuration_file(CONFIG_PATH).unwrap();
         let result = config.stringify();
         // Validate that the output is a String.
         assert_eq!(std::any::type_name_of_val(&result), "alloc::string::String");
     }
+
+    #[test]
+    fn test_read_invalid_configuration_file() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("invalid.conf");
+        fs::write(
+            &path,
+            "[database]\nplan = \"startr\"\nversion = \"9\"\nenableHighAvailability = false\ncidrBlocks = []\n",
+        )
+        .unwrap();
+
+        let result = Conf::read_configuration_file(path.to_str().unwrap());
+        let errors = result.unwrap_err().downcast::<ValidationErrors>().unwrap();
+
+        // Every problem is reported at once.
+        assert_eq!(errors.0.len(), 2);
+        assert_eq!(errors.0[0].line, Some(2));
+        assert_eq!(errors.0[1].line, Some(3));
+    }
+
+    #[test]
+    fn test_blank_cidr_blocks_stay_private() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("private.conf");
+        fs::write(
+            &path,
+            "[redis]\nname = \"\"\nplan = \"starter\"\ncidrBlocks = []\n",
+        )
+        .unwrap();
+
+        let config = Conf::read_configuration_file(path.to_str().unwrap()).unwrap();
+        assert!(config.redis.unwrap().cidrBlocks.is_empty());
+    }
+
+    #[test]
+    fn test_read_environment_overlay() {
+        let temp = tempfile::tempdir().unwrap();
+        let directory = temp.path();
+        let base = directory.join("app.conf");
+        fs::write(
+            &base,
+            r#"
+[database]
+name = "orders"
+plan = "starter"
+version = "16"
+enableHighAvailability = false
+cidrBlocks = [{ cidrBlock = "10.0.0.0/16", description = "VPC" }]
+
+[[services]]
+type = "web_service"
+name = "api"
+repo = "https://github.com/lexara-prime-ai/SAMPLE_WEB_SERVICE"
+serviceDetails = { region = "oregon", plan = "starter", numInstances = 1 }
+envVars = [
+    { key = "LOG_LEVEL", value = "debug" },
+    { key = "PORT", value = "8080" }
+]
+"#,
+        )
+        .unwrap();
+        fs::write(
+            directory.join("app.production.conf"),
+            r#"
+[database]
+plan = "pro"
+enableHighAvailability = true
+
+[[services]]
+name = "api"
+serviceDetails = { plan = "standard", numInstances = 3 }
+envVars = [{ key = "LOG_LEVEL", value = "warn" }]
+"#,
+        )
+        .unwrap();
+
+        let config = Conf::read_environment(base.to_str().unwrap(), "production").unwrap();
+        let database = config.database.as_ref().unwrap();
+        let details = config.services[0].service_details.as_ref().unwrap();
+
+        assert_eq!(database.plan, "pro");
+        assert!(database.enableHighAvailability);
+        assert_eq!(database.version, "16");
+        assert_eq!(details.region, Some(Region::Oregon));
+        assert_eq!(details.num_instances, Some(3));
+        assert_eq!(
+            config.services[0]
+                .env_vars
+                .iter()
+                .map(|v| (v.key.as_str(), v.value.as_deref().unwrap()))
+                .collect::<Vec<_>>(),
+            vec![("LOG_LEVEL", "warn"), ("PORT", "8080")]
+        );
+        assert!(config.to_toml().unwrap().contains("plan = \"pro\""));
+
+        // Unknown environments fail loudly.
+        assert!(Conf::read_environment(base.to_str().unwrap(), "staging").is_err());
+    }
+
+    #[test]
+    fn test_naming_policy() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("naming.conf");
+        fs::write(
+            &path,
+            r#"
+[naming]
+template = "{project}-{env}-{kind}"
+project = "orders"
+environment = "staging"
+
+[database]
+databaseName = ""
+databaseUser = ""
+name = ""
+plan = "starter"
+version = "16"
+enableHighAvailability = false
+cidrBlocks = []
+"#,
+        )
+        .unwrap();
+
+        let config = Conf::read_configuration_file(path.to_str().unwrap()).unwrap();
+        let database = config.database.unwrap();
+
+        assert_eq!(database.name.as_deref(), Some("orders-staging-postgres"));
+        assert_eq!(database.databaseName.as_deref(), Some("orders_staging_db"));
+        assert_eq!(
+            database.databaseUser.as_deref(),
+            Some("orders_staging_user")
+        );
+    }
+
+    #[test]
+    fn test_read_yaml_and_json_configuration_files() {
+        let toml = Conf::read_configuration_file(CONFIG_PATH).unwrap();
+
+        for config_path in ["./samples/sample.yaml", "./samples/sample.json"] {
+            let config = Conf::read_configuration_file(config_path).unwrap();
+            let (expected, database) = (toml.database.as_ref().unwrap(), config.database.unwrap());
+
+            assert_eq!(database.plan, expected.plan);
+            assert_eq!(database.version, expected.version);
+            assert_eq!(
+                database.cidrBlocks[0].cidrBlock,
+                expected.cidrBlocks[0].cidrBlock
+            );
+            assert_eq!(
+                config.redis.unwrap().plan,
+                toml.redis.as_ref().unwrap().plan
+            );
+        }
+
+        // Round trip through every format.
+        let directory = tempfile::tempdir().unwrap();
+        for extension in ["toml", "yaml", "json"] {
+            let path = directory.path().join(format!("round_trip.{}", extension));
+            toml.write_configuration_file(path.to_str().unwrap())
+                .unwrap();
+
+            let config = Conf::read_configuration_file(path.to_str().unwrap()).unwrap();
+            assert_eq!(config.stringify(), toml.stringify());
+        }
+    }
 }
@@ -0,0 +1,92 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+// [JSON] parsing.
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+// Idiomatic [ERROR] handling.
+use anyhow::{Context, Error};
+
+use std::path::Path;
+
+/// The formats a configuration can be read from and written to, sharing the same schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Toml,
+    Yaml,
+    Json,
+}
+
+impl ConfigFormat {
+    /// Detects the format from the file extension, then from the contents.
+    pub fn detect(path: &str, contents: &str) -> Self {
+        Self::from_extension(path).unwrap_or_else(|| Self::from_contents(contents))
+    }
+
+    /// `.conf` and `.toml` files are TOML, `.yaml`/`.yml` YAML and `.json` JSON.
+    pub fn from_extension(path: &str) -> Option<Self> {
+        let extension = Path::new(path).extension()?.to_str()?.to_lowercase();
+
+        match extension.as_str() {
+            "conf" | "toml" => Some(ConfigFormat::Toml),
+            "yaml" | "yml" => Some(ConfigFormat::Yaml),
+            "json" => Some(ConfigFormat::Json),
+            _ => None,
+        }
+    }
+
+    pub fn from_contents(contents: &str) -> Self {
+        let trimmed = contents.trim_start();
+
+        if trimmed.starts_with('{') {
+            ConfigFormat::Json
+        } else if toml::from_str::<toml::Table>(contents).is_ok() {
+            ConfigFormat::Toml
+        } else {
+            ConfigFormat::Yaml
+        }
+    }
+
+    pub fn parse<T: DeserializeOwned>(&self, contents: &str) -> Result<T, Error> {
+        match self {
+            ConfigFormat::Toml => toml::from_str(contents).map_err(Error::from),
+            ConfigFormat::Yaml => serde_yaml::from_str(contents).map_err(Error::from),
+            ConfigFormat::Json => serde_json::from_str(contents).map_err(Error::from),
+        }
+    }
+
+    pub fn serialize<T: Serialize>(&self, value: &T) -> Result<String, Error> {
+        match self {
+            ConfigFormat::Toml => toml::to_string_pretty(value).map_err(Error::from),
+            ConfigFormat::Yaml => serde_yaml::to_string(value).map_err(Error::from),
+            ConfigFormat::Json => serde_json::to_string_pretty(value).map_err(Error::from),
+        }
+        .with_context(|| format!("Unable to SERIALIZE configuration as {:?}.", self))
+    }
+}
+
+#[cfg(test)]
+mod format_tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_format() {
+        assert_eq!(ConfigFormat::detect("app.conf", ""), ConfigFormat::Toml);
+        assert_eq!(ConfigFormat::detect("app.YML", ""), ConfigFormat::Yaml);
+        assert_eq!(ConfigFormat::detect("app.json", ""), ConfigFormat::Json);
+
+        // Unknown extensions fall back to the contents.
+        assert_eq!(
+            ConfigFormat::detect("app.cfg", "  {\"redis\": {}}"),
+            ConfigFormat::Json
+        );
+        assert_eq!(
+            ConfigFormat::detect("app.cfg", "[redis]\nplan = \"starter\"\n"),
+            ConfigFormat::Toml
+        );
+        assert_eq!(
+            ConfigFormat::detect("app.cfg", "redis:\n  plan: starter\n"),
+            ConfigFormat::Yaml
+        );
+    }
+}
@@ -0,0 +1,208 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+// Idiomatic [ERROR] handling.
+use anyhow::{Context, Error};
+
+use std::collections::BTreeMap;
+
+// [JSON] parsing.
+use serde_json::Value;
+
+// [render_cdk] modules.
+use crate::environment_management::environment_manager::load_dot_env;
+use crate::utils::format::ConfigFormat;
+
+/// The table variables are declared in, i.e `[variables]`.
+pub const VARIABLES_SECTION: &str = "variables";
+
+/// Reads the `[variables]` table, without parsing the rest of the (uninterpolated) file.
+///
+/// YAML and JSON files are parsed as is, so their `variables` are only read when
+/// the file is valid before substitution.
+pub fn extract_variables(
+    contents: &str,
+    format: ConfigFormat,
+) -> Result<BTreeMap<String, String>, Error> {
+    let table = match format {
+        ConfigFormat::Toml => {
+            let mut section = String::new();
+            let mut inside = false;
+
+            for line in contents.lines() {
+                let trimmed = line.trim_start();
+                if trimmed.starts_with('[') {
+                    inside = trimmed.trim_end() == format!("[{}]", VARIABLES_SECTION);
+                    continue;
+                }
+                if inside {
+                    section.push_str(line);
+                    section.push('\n');
+                }
+            }
+
+            let table: toml::Table =
+                toml::from_str(&section).context("Unable to PARSE [variables].")?;
+            serde_json::to_value(table)?
+        }
+        _ => match format.parse::<Value>(contents) {
+            Ok(mut value) => value[VARIABLES_SECTION].take(),
+            Err(_) => Value::Null,
+        },
+    };
+
+    let Value::Object(table) = table else {
+        return Ok(BTreeMap::new());
+    };
+
+    table
+        .into_iter()
+        .map(|(name, value)| match value {
+            Value::String(value) => Ok((name, value)),
+            Value::Number(_) | Value::Bool(_) => Ok((name, value.to_string())),
+            _ => Err(anyhow::anyhow!(
+                "[variables] `{}` must be a string, number or boolean.",
+                name
+            )),
+        })
+        .collect()
+}
+
+/// Substitutes `${VAR}` and `${VAR:-default}`, `$${` is kept as a literal `${`.
+///
+/// Values are read from the process environment (including the `.env` file), then `variables`,
+/// then the default. Empty values fall back to the default, like in a shell.
+pub fn interpolate(
+    contents: &str,
+    variables: &BTreeMap<String, String>,
+    file: &str,
+) -> Result<String, Error> {
+    load_dot_env();
+
+    let mut output = String::with_capacity(contents.len());
+    let mut undefined = vec![];
+
+    for (index, line) in contents.lines().enumerate() {
+        if index > 0 {
+            output.push('\n');
+        }
+
+        // Comments are kept as is.
+        if line.trim_start().starts_with('#') {
+            output.push_str(line);
+            continue;
+        }
+
+        let mut rest = line;
+        while let Some(start) = rest.find("${") {
+            if rest[..start].ends_with('$') {
+                output.push_str(&rest[..start - 1]);
+                output.push_str("${");
+                rest = &rest[start + 2..];
+                continue;
+            }
+
+            output.push_str(&rest[..start]);
+            let Some(end) = rest[start..].find('}') else {
+                return Err(anyhow::anyhow!(
+                    "{}:{}: Unterminated variable `{}`",
+                    file,
+                    index + 1,
+                    &rest[start..]
+                ));
+            };
+
+            let expression = &rest[start + 2..start + end];
+            let (name, default) = match expression.split_once(":-") {
+                Some((name, default)) => (name.trim(), Some(default)),
+                None => (expression.trim(), None),
+            };
+
+            let value = std::env::var(name)
+                .ok()
+                .or_else(|| variables.get(name).cloned())
+                .filter(|value| !value.is_empty() || default.is_none())
+                .or_else(|| default.map(str::to_owned));
+
+            match value {
+                Some(value) => output.push_str(&value),
+                None => undefined.push(format!("{}:{}: `${{{}}}`", file, index + 1, name)),
+            }
+
+            rest = &rest[start + end + 1..];
+        }
+        output.push_str(rest);
+    }
+
+    if contents.ends_with('\n') {
+        output.push('\n');
+    }
+
+    if !undefined.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Found undefined variable(s), set them in the environment or [variables]:\n  - {}",
+            undefined.join("\n  - ")
+        ));
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod interpolation_tests {
+    use super::*;
+
+    // `PATH` is read from the environment without modifying it, since tests run in parallel.
+    const SOURCE: &str = r#"
+[variables]
+RENDER_CDK_TEST_BRANCH = "feature-x"
+RENDER_CDK_TEST_REGION = "oregon"
+PATH = "overridden"
+instances = 2
+
+[database]
+# ${NOT_INTERPOLATED}
+name = "orders-${RENDER_CDK_TEST_BRANCH}"
+region = "${RENDER_CDK_TEST_REGION}"
+plan = "${RENDER_CDK_TEST_PLAN:-starter}"
+description = "$${literal}"
+path = "${PATH}"
+numInstances = ${instances}
+"#;
+
+    #[test]
+    fn test_interpolate() {
+        let variables = extract_variables(SOURCE, ConfigFormat::Toml).unwrap();
+        let result = interpolate(SOURCE, &variables, "app.conf").unwrap();
+
+        assert!(result.contains("# ${NOT_INTERPOLATED}"));
+        assert!(result.contains("name = \"orders-feature-x\""));
+        assert!(result.contains("region = \"oregon\""));
+        assert!(result.contains("plan = \"starter\""));
+        assert!(result.contains("description = \"${literal}\""));
+        assert!(result.contains("numInstances = 2"));
+
+        // The environment takes precedence over [variables].
+        let path = std::env::var("PATH").unwrap();
+        assert!(result.contains(&format!("path = \"{}\"", path)));
+    }
+
+    #[test]
+    fn test_undefined_variables() {
+        let source = "[redis]\nname = \"${RENDER_CDK_UNDEFINED_NAME}\"\nplan = \"${RENDER_CDK_UNDEFINED_PLAN}\"\n";
+        let message = interpolate(source, &BTreeMap::new(), "app.conf")
+            .unwrap_err()
+            .to_string();
+
+        assert!(message.contains("app.conf:2: `${RENDER_CDK_UNDEFINED_NAME}`"));
+        assert!(message.contains("app.conf:3: `${RENDER_CDK_UNDEFINED_PLAN}`"));
+    }
+
+    #[test]
+    fn test_yaml_variables() {
+        let source = "variables:\n  plan: standard\nredis:\n  plan: ${plan}\n";
+        let variables = extract_variables(source, ConfigFormat::Yaml).unwrap();
+        let result = interpolate(source, &variables, "app.yaml").unwrap();
+
+        assert!(result.contains("plan: standard\nredis:\n  plan: standard"));
+    }
+}
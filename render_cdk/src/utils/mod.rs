@@ -1,4 +1,10 @@
 pub mod config;
+pub mod format;
+pub mod interpolation;
+pub mod naming;
+pub mod overlay;
 pub mod random;
 pub mod request_manager;
+pub mod schema;
 pub mod stringify;
+pub mod validation;
@@ -0,0 +1,284 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+// [JSON] parsing.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// Idiomatic [ERROR] handling.
+use anyhow::Error;
+
+// [render_cdk] modules.
+use crate::resource_management::services::service_manager::{
+    ServiceManager, ServiceManagerOperations,
+};
+use crate::utils::config::Conf;
+use crate::utils::random::GENERATE_RANDOM_STRING;
+use crate::utils::validation::MAX_NAME_LENGTH;
+
+/// Placeholders a [NamingPolicy] template can contain.
+pub const PLACEHOLDERS: &[&str] = &["{project}", "{env}", "{kind}", "{suffix}"];
+
+/// How the end of a generated name is made unique.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Suffix {
+    /// The rendered template is used as is.
+    #[default]
+    None,
+    /// A hash of the rendered template and `seed`, stable across runs.
+    Seeded,
+    /// Random characters, different on every run.
+    Random,
+}
+
+/// Generates names from a template i.e `{project}-{env}-{kind}`, read from a `[naming]` section.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct NamingPolicy {
+    #[serde(default = "default_template")]
+    pub template: String,
+    #[serde(default)]
+    pub project: String,
+    #[serde(default)]
+    pub environment: String,
+    #[serde(default)]
+    pub suffix: Suffix,
+    #[serde(default = "default_suffix_length", rename = "suffixLength")]
+    pub suffix_length: usize,
+    #[serde(default)]
+    pub seed: String,
+    #[serde(default = "default_max_length", rename = "maxLength")]
+    pub max_length: usize,
+}
+
+fn default_template() -> String {
+    "{project}-{env}-{kind}".to_owned()
+}
+
+fn default_suffix_length() -> usize {
+    6
+}
+
+fn default_max_length() -> usize {
+    MAX_NAME_LENGTH
+}
+
+impl Default for NamingPolicy {
+    fn default() -> Self {
+        Self::new(&default_template())
+    }
+}
+
+impl NamingPolicy {
+    pub fn new(template: &str) -> Self {
+        Self {
+            template: template.to_owned(),
+            project: String::new(),
+            environment: String::new(),
+            suffix: Suffix::None,
+            suffix_length: default_suffix_length(),
+            seed: String::new(),
+            max_length: default_max_length(),
+        }
+    }
+
+    pub fn project(mut self, project: &str) -> Self {
+        self.project = project.to_owned();
+        self
+    }
+
+    pub fn environment(mut self, environment: &str) -> Self {
+        self.environment = environment.to_owned();
+        self
+    }
+
+    pub fn suffix(mut self, suffix: Suffix) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    pub fn seed(mut self, seed: &str) -> Self {
+        self.seed = seed.to_owned();
+        self
+    }
+
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// A resource name i.e `orders-production-postgres`, limited to `[a-z0-9-_]` and [NamingPolicy::max_length].
+    pub fn name(&self, kind: &str) -> String {
+        let rendered = self
+            .template
+            .replace("{project}", &self.project)
+            .replace("{env}", &self.environment)
+            .replace("{kind}", kind);
+
+        let suffix = match self.suffix {
+            Suffix::None => String::new(),
+            Suffix::Seeded => {
+                let hash = fnv1a(&format!(
+                    "{}:{}",
+                    self.seed,
+                    rendered.replace("{suffix}", "")
+                ));
+                format!("{:016x}", hash)
+                    .chars()
+                    .take(self.suffix_length.min(16))
+                    .collect()
+            }
+            Suffix::Random => GENERATE_RANDOM_STRING(self.suffix_length).to_lowercase(),
+        };
+
+        // The suffix is appended, unless the template places it.
+        let (base, suffix) = if rendered.contains("{suffix}") {
+            (rendered.replace("{suffix}", &suffix), String::new())
+        } else {
+            (rendered, suffix)
+        };
+
+        let mut base = sanitize(&base);
+        let max_length = self.max_length.min(MAX_NAME_LENGTH);
+
+        if suffix.is_empty() {
+            base.truncate(max_length);
+            return base.trim_end_matches(['-', '_']).to_owned();
+        }
+
+        base.truncate(max_length.saturating_sub(suffix.len() + 1));
+        let base = base.trim_end_matches(['-', '_']);
+        if base.is_empty() {
+            suffix
+        } else {
+            format!("{}-{}", base, suffix)
+        }
+    }
+
+    /// A Postgres identifier i.e `orders_production_db`, for database names and users.
+    pub fn identifier(&self, kind: &str) -> String {
+        let name = self.name(kind).replace('-', "_");
+
+        if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            name
+        } else {
+            let mut name = format!("db_{}", name);
+            name.truncate(self.max_length.min(MAX_NAME_LENGTH));
+            name
+        }
+    }
+}
+
+/// Lowercases a name, replacing unsupported characters with `-`.
+pub fn sanitize(name: &str) -> String {
+    let mut sanitized = String::with_capacity(name.len());
+
+    for c in name.to_lowercase().chars() {
+        let c = if c.is_ascii_alphanumeric() || c == '_' {
+            c
+        } else {
+            '-'
+        };
+
+        if !(c == '-' && (sanitized.is_empty() || sanitized.ends_with('-'))) {
+            sanitized.push(c);
+        }
+    }
+
+    sanitized.trim_end_matches('-').to_owned()
+}
+
+/// FNV-1a, stable across platforms and Rust versions unlike [std::hash::DefaultHasher].
+fn fnv1a(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Fails when a resource in the configuration has the same name as an existing one.
+pub async fn check_name_collisions(config: &Conf) -> Result<(), Error> {
+    let mut collisions = vec![];
+
+    if let Some(name) = config.database.as_ref().and_then(|d| d.name.as_deref()) {
+        let found = ServiceManager::find_postgres_instance_by_name(name, true, "100").await?;
+        if contains_name(&found, "postgres", name) {
+            collisions.push(format!("postgres {:?}", name));
+        }
+    }
+
+    if let Some(name) = config.redis.as_ref().and_then(|r| r.name.as_deref()) {
+        let found = ServiceManager::find_redis_instance_by_name(name, "100").await?;
+        if contains_name(&found, "redis", name) {
+            collisions.push(format!("redis {:?}", name));
+        }
+    }
+
+    for service in &config.services {
+        let found =
+            ServiceManager::find_service_by_name_and_type(&service.name, service.type_.clone())
+                .await?;
+        if contains_name(&found, "service", &service.name) {
+            collisions.push(format!("{} {:?}", service.type_, service.name));
+        }
+    }
+
+    if collisions.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(
+            "The following resources already exist: {}.",
+            collisions.join(", ")
+        ))
+    }
+}
+
+fn contains_name(found: &Value, key: &str, name: &str) -> bool {
+    found
+        .as_array()
+        .is_some_and(|items| items.iter().any(|item| item[key]["name"] == name))
+}
+
+#[cfg(test)]
+mod naming_tests {
+    use super::*;
+    use crate::testing::prelude::*;
+
+    #[test]
+    fn test_name_from_template() {
+        let policy = NamingPolicy::default()
+            .project("Orders API")
+            .environment("production");
+
+        assert_eq!(policy.name("postgres"), "orders-api-production-postgres");
+        assert_eq!(policy.identifier("db"), "orders_api_production_db");
+
+        // Seeded suffixes are stable, and the name is truncated before them.
+        let seeded = policy.clone().suffix(Suffix::Seeded).max_length(20);
+        assert_eq!(seeded.name("redis"), seeded.name("redis"));
+        assert_ne!(seeded.name("redis"), seeded.name("postgres"));
+        assert_eq!(seeded.name("redis").len(), 20);
+        assert!(seeded.name("redis").starts_with("orders-api-p"));
+
+        let random = NamingPolicy::new("{kind}-{suffix}").suffix(Suffix::Random);
+        assert!(random.name("redis").starts_with("redis-"));
+        assert_eq!(random.name("redis").len(), 12);
+    }
+
+    #[tokio::test]
+    async fn test_check_name_collisions() {
+        shared();
+
+        let mut config: Conf = toml::from_str(
+            "[redis]\nname = \"fluentcomet\"\nplan = \"starter\"\ncidrBlocks = []\n",
+        )
+        .unwrap();
+        let result = check_name_collisions(&config).await;
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("redis \"fluentcomet\""));
+
+        config.redis.as_mut().unwrap().name = Some("orders-production-redis".to_owned());
+        assert!(check_name_collisions(&config).await.is_ok());
+    }
+}
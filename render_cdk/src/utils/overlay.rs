@@ -0,0 +1,115 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+// [JSON] parsing.
+use serde_json::Value;
+
+/// Keys identifying the entries of arrays of tables, i.e `[[services]]` by `name`.
+pub const IDENTITY_KEYS: &[&str] = &["name", "key"];
+
+/// Deep merges an overlay into a base configuration.
+///
+/// Tables are merged key by key and arrays of tables sharing an identity key
+/// (see [IDENTITY_KEYS]) entry by entry, any other value is replaced,
+/// i.e `cidrBlocks` in an overlay replace the base ones.
+pub fn merge(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (Value::Array(base), Value::Array(overlay)) => match identity_key(base, &overlay) {
+            Some(identity) => {
+                for entry in overlay {
+                    match base
+                        .iter_mut()
+                        .find(|existing| existing[identity] == entry[identity])
+                    {
+                        Some(existing) => merge(existing, entry),
+                        None => base.push(entry),
+                    }
+                }
+            }
+            None => *base = overlay,
+        },
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// The first identity key every entry of both arrays has.
+fn identity_key(base: &[Value], overlay: &[Value]) -> Option<&'static str> {
+    IDENTITY_KEYS.iter().copied().find(|identity| {
+        base.iter()
+            .chain(overlay)
+            .all(|entry| entry.get(identity).is_some_and(|value| !value.is_null()))
+    })
+}
+
+#[cfg(test)]
+mod overlay_tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_merge() {
+        let mut base = json!({
+            "database": {
+                "plan": "starter",
+                "cidrBlocks": [
+                    { "cidrBlock": "10.0.0.0/16", "description": "VPC" },
+                    { "cidrBlock": "10.1.0.0/16", "description": "Peered VPC" }
+                ]
+            },
+            "services": [
+                {
+                    "name": "api",
+                    "serviceDetails": { "region": "oregon", "numInstances": 1 },
+                    "envVars": [{ "key": "LOG_LEVEL", "value": "debug" }, { "key": "PORT", "value": "80" }]
+                },
+                { "name": "docs" }
+            ]
+        });
+
+        merge(
+            &mut base,
+            json!({
+                "database": {
+                    "plan": "pro",
+                    "cidrBlocks": [{ "cidrBlock": "10.2.0.0/16", "description": "Prod VPC" }]
+                },
+                "services": [
+                    {
+                        "name": "api",
+                        "serviceDetails": { "numInstances": 3 },
+                        "envVars": [{ "key": "LOG_LEVEL", "value": "warn" }]
+                    },
+                    { "name": "worker" }
+                ]
+            }),
+        );
+
+        assert_eq!(
+            base,
+            json!({
+                "database": {
+                    "plan": "pro",
+                    "cidrBlocks": [{ "cidrBlock": "10.2.0.0/16", "description": "Prod VPC" }]
+                },
+                "services": [
+                    {
+                        "name": "api",
+                        "serviceDetails": { "region": "oregon", "numInstances": 3 },
+                        "envVars": [{ "key": "LOG_LEVEL", "value": "warn" }, { "key": "PORT", "value": "80" }]
+                    },
+                    { "name": "docs" },
+                    { "name": "worker" }
+                ]
+            })
+        );
+    }
+}
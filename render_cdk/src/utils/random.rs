@@ -47,7 +47,7 @@ pub fn GENERATE_UNIQUE_NAME() -> String {
         "Orion", "Stratus", "Nimbus", "Cosmos", "Eclipse", "Helix", "Photon", "Spectra", "Aurora",
         "Cypher", "Terra", "Quasar", "Solstice", "Astro", "Comet", "Lunar", "Matrix", "Atlas",
         "Inferno", "Blaze", "Cyclone", "Titan", "Galaxy", "Horizon", "Mistral", "Phoenix",
-        "Radiance", "Meteor", "Plasma", "Inferno", "Echo", "Vanguard", "Stellar", "Fusion", "Halo",
+        "Radiance", "Meteor", "Plasma", "Echo", "Vanguard", "Stellar", "Fusion", "Halo",
         "Spectrum", "Zephyr", "Draco", "Equinox", "Falcon",
     ];
 
@@ -73,6 +73,38 @@ macro_rules! create_post_request {
     };
 }
 
+/// Creates and sends a `PATCH` request with a payload using the provided HTTP client.
+///
+/// # Parameters
+///
+/// * `$client`: The HTTP client instance used to send the request.
+/// * `$api_url`: The URL to which the request is sent.
+/// * `$api_key`: The API key for authorization, which is used as a Bearer token.
+/// * `$payload`: The fields to update.
+/// * `$process`: A string or identifier used to identify the process, for logging and error context.
+///
+/// # Example
+///
+/// ```ignore
+/// let response = create_patch_request!(client, "https://api.example.com/data/id", api_key, payload, "update_service").await?;
+/// ```
+///
+/// This macro sets the required headers (`ACCEPT`, `CONTENT_TYPE`, and `AUTHORIZATION`), adds the provided payload, and sends a PATCH request.
+#[macro_export]
+macro_rules! create_patch_request {
+    ($client: expr, $api_url: expr, $api_key: expr, $payload: expr, $process: expr) => {
+        $client
+            .patch($api_url)
+            .header(ACCEPT, "application/json")
+            .header(CONTENT_TYPE, "application/json")
+            .header(AUTHORIZATION, format!("Bearer {}", $api_key))
+            .body($payload)
+            .send()
+            .await
+            .context(format!("Error sending request :: {:?}", $process))
+    };
+}
+
 /// Creates and sends a `DELETE` request using the provided HTTP client.
 ///
 /// # Parameters
@@ -115,14 +147,20 @@ macro_rules! create_delete_request {
 /// let result = handle_response!(response, "<create_service>");
 /// ```
 ///
-/// If the response status is successful, the result is logged and returned as JSON data. Otherwise, an error is logged
-/// with a custom message including the provided process name.
+/// If the response status is successful, the result is logged and returned as JSON data (`Value::Null` for an empty body).
+/// Otherwise, an error is logged with a custom message including the provided process name.
 #[macro_export]
 macro_rules! handle_response {
     ($response: expr, $process: expr) => {
         if $response.status().is_success() {
             let result = $response.text().await.context("Error parsing response.")?;
-            let data: Value = serde_json::from_str(&result)?;
+
+            // i.e <204 No Content> on deletion.
+            let data: Value = if result.trim().is_empty() {
+                Value::Null
+            } else {
+                serde_json::from_str(&result)?
+            };
 
             LOGGER!("<response> -> ", format!("{:#?}", data), LogLevel::SUCCESS);
 
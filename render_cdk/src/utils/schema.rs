@@ -0,0 +1,96 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+// [JSON] parsing.
+use schemars::schema_for;
+use serde_json::Value;
+
+// Idiomatic [ERROR] handling.
+use anyhow::{Context, Error};
+
+use std::fs;
+use std::path::Path;
+
+// [render_cdk] modules.
+use crate::resource_management::models::template::Template;
+use crate::utils::config::Conf;
+
+/// Where the generated schemas are committed, relative to the crate root.
+pub const SCHEMA_DIRECTORY: &str = "./schemas";
+
+/// Regenerates the committed schemas when set, i.e `RENDER_CDK_UPDATE_SCHEMAS=1 cargo test schema`.
+pub const UPDATE_VARIABLE: &str = "RENDER_CDK_UPDATE_SCHEMAS";
+
+/// The JSON Schema of configuration files, in any format.
+pub fn configuration_schema() -> Value {
+    serde_json::to_value(schema_for!(Conf)).expect("Unable to SERIALIZE [Conf] schema.")
+}
+
+/// The JSON Schema of a single service [Template].
+pub fn template_schema() -> Value {
+    serde_json::to_value(schema_for!(Template)).expect("Unable to SERIALIZE [Template] schema.")
+}
+
+/// Every schema, along with its file name.
+pub fn schemas() -> Vec<(&'static str, Value)> {
+    vec![
+        ("conf.schema.json", configuration_schema()),
+        ("template.schema.json", template_schema()),
+    ]
+}
+
+pub fn render(schema: &Value) -> String {
+    format!(
+        "{}\n",
+        serde_json::to_string_pretty(schema).expect("Unable to SERIALIZE schema.")
+    )
+}
+
+pub fn write_schemas(directory: &str) -> Result<(), Error> {
+    fs::create_dir_all(directory)
+        .with_context(|| format!("Unable to CREATE schema directory: {:?}", directory))?;
+
+    for (file_name, schema) in schemas() {
+        let path = Path::new(directory).join(file_name);
+        fs::write(&path, render(&schema))
+            .with_context(|| format!("Unable to WRITE schema: {:?}", path))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod schema_tests {
+    use super::*;
+
+    #[test]
+    fn test_committed_schemas_are_up_to_date() {
+        if std::env::var(UPDATE_VARIABLE).is_ok_and(|value| value == "1") {
+            write_schemas(SCHEMA_DIRECTORY).unwrap();
+        }
+
+        for (file_name, schema) in schemas() {
+            let path = Path::new(SCHEMA_DIRECTORY).join(file_name);
+            let committed = fs::read_to_string(&path).unwrap_or_default();
+
+            assert!(
+                committed == render(&schema),
+                "{:?} is out of date, run `{}=1 cargo test schema`.",
+                path,
+                UPDATE_VARIABLE
+            );
+        }
+    }
+
+    #[test]
+    fn test_configuration_schema() {
+        let schema = configuration_schema();
+        let database = &schema["$defs"]["DatabaseConf"];
+
+        assert_eq!(schema["title"], "Conf");
+        assert!(database["required"]
+            .as_array()
+            .unwrap()
+            .contains(&"plan".into()));
+        assert!(schema["$defs"]["ServiceDetails"]["properties"]["numInstances"].is_object());
+    }
+}
@@ -0,0 +1,821 @@
+#![allow(missing_docs)]
+#![allow(unused)]
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// [render_cdk] modules.
+use crate::resource_management::models::caching::CacheConf;
+use crate::resource_management::models::disk::Disk;
+use crate::resource_management::models::enums::{
+    MaxmemoryPolicy, Region, Runtime, ServicePlan, ServiceType, Toggle,
+};
+use crate::resource_management::models::postgres::PostgresUpdate;
+use crate::resource_management::models::redis::RedisUpdate;
+use crate::resource_management::models::storage::DatabaseConf;
+use crate::resource_management::models::template::{ServiceDetails, ServiceUpdate, Template};
+use crate::utils::config::Conf;
+use crate::utils::naming::{NamingPolicy, PLACEHOLDERS};
+
+// [DEBUG] utils.
+use crate::logger::prelude::*;
+use crate::LOGGER;
+use colored::Colorize;
+
+// Predefined [CONSTANTS].
+pub const REGIONS: &[&str] = Region::VALUES;
+
+pub const POSTGRES_VERSIONS: &[&str] = &["11", "12", "13", "14", "15", "16", "17"];
+
+pub const POSTGRES_PLANS: &[&str] = &[
+    // Legacy plans.
+    "free",
+    "starter",
+    "standard",
+    "pro",
+    "pro_plus",
+    "custom",
+    // Flexible plans.
+    "basic_256mb",
+    "basic_1gb",
+    "basic_4gb",
+    "pro_4gb",
+    "pro_8gb",
+    "pro_16gb",
+    "pro_32gb",
+    "pro_64gb",
+    "pro_128gb",
+    "pro_192gb",
+    "pro_256gb",
+    "pro_384gb",
+    "pro_512gb",
+    "accelerated_16gb",
+    "accelerated_32gb",
+    "accelerated_64gb",
+    "accelerated_128gb",
+    "accelerated_256gb",
+    "accelerated_384gb",
+    "accelerated_512gb",
+    "accelerated_768gb",
+    "accelerated_1024gb",
+];
+
+pub const REDIS_PLANS: &[&str] = &["free", "starter", "standard", "pro", "pro_plus", "custom"];
+
+pub const SERVICE_PLANS: &[&str] = ServicePlan::VALUES;
+
+pub const SERVICE_TYPES: &[&str] = ServiceType::VALUES;
+
+pub const RUNTIMES: &[&str] = Runtime::VALUES;
+
+pub const TOGGLES: &[&str] = Toggle::VALUES;
+
+/// Resource names are used in hostnames, so they follow DNS label rules.
+pub const MAX_NAME_LENGTH: usize = 63;
+
+/// A single problem, located by its field path i.e `database.cidrBlocks[0].cidrBlock`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    pub file: Option<String>,
+    pub line: Option<usize>,
+    pub field: String,
+    pub message: String,
+}
+
+impl ValidationError {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            line: None,
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.file, self.line) {
+            (Some(file), Some(line)) => write!(f, "{}:{}: ", file, line)?,
+            (Some(file), None) => write!(f, "{}: ", file)?,
+            (None, Some(line)) => write!(f, "line {}: ", line)?,
+            (None, None) => {}
+        }
+
+        write!(f, "`{}` {}", self.field, self.message)
+    }
+}
+
+/// Every problem found in a configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationErrors(pub Vec<ValidationError>);
+
+impl ValidationErrors {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ValidationError> {
+        self.0.iter()
+    }
+
+    /// Attaches the file and the line each field is defined on.
+    pub fn locate(mut self, source: &str, file: &str) -> Self {
+        for error in self.0.iter_mut() {
+            error.file = Some(file.to_owned());
+            error.line = locate_field(source, &error.field);
+        }
+        self
+    }
+
+    /// Like [ValidationErrors::locate], for a configuration merged from several sources.
+    /// Each field is attributed to the last source defining it.
+    pub fn locate_in(mut self, sources: &[(String, String)]) -> Self {
+        for error in self.0.iter_mut() {
+            let located = sources.iter().rev().find_map(|(file, source)| {
+                locate_field(source, &error.field).map(|line| (file, line))
+            });
+
+            match located {
+                Some((file, line)) => {
+                    error.file = Some(file.clone());
+                    error.line = Some(line);
+                }
+                None => error.file = sources.first().map(|(file, _)| file.clone()),
+            }
+        }
+        self
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Found {} configuration error(s):", self.0.len())?;
+        for error in &self.0 {
+            writeln!(f, "  - {}", error)?;
+        }
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+pub trait Validate {
+    /// Collects every problem, rather than stopping at the first one.
+    fn validate(&self) -> ValidationErrors;
+}
+
+impl Validate for Conf {
+    fn validate(&self) -> ValidationErrors {
+        let mut errors = vec![];
+
+        if let Some(database) = &self.database {
+            validate_database(database, "database", &mut errors);
+        }
+
+        if let Some(redis) = &self.redis {
+            validate_redis(redis, "redis", &mut errors);
+        }
+
+        if let Some(naming) = &self.naming {
+            validate_naming(naming, "naming", &mut errors);
+        }
+
+        for (index, service) in self.services.iter().enumerate() {
+            for mut error in service.validate().0 {
+                error.field = format!("services[{}].{}", index, error.field);
+                errors.push(error);
+            }
+        }
+
+        ValidationErrors(errors)
+    }
+}
+
+impl Validate for Template {
+    fn validate(&self) -> ValidationErrors {
+        let mut errors = vec![];
+
+        check_name(&self.name, "name", true, &mut errors);
+        check_variant(self.type_.as_str(), "type", SERVICE_TYPES, &mut errors);
+
+        if self.image.is_none() {
+            if self.repo.is_empty() {
+                errors.push(ValidationError::new(
+                    "repo",
+                    "is required when no `image` is provided",
+                ));
+            } else if !self.repo.starts_with("https://") {
+                errors.push(ValidationError::new(
+                    "repo",
+                    format!("must be an https:// url, found {:?}", self.repo),
+                ));
+            }
+        }
+
+        if let Some(auto_deploy) = &self.auto_deploy {
+            check_variant(auto_deploy.as_str(), "autoDeploy", TOGGLES, &mut errors);
+        }
+
+        if let Some(details) = &self.service_details {
+            validate_service_details(details, &mut errors);
+        }
+
+        let details = self.service_details.as_ref();
+        let schedule = details.and_then(|details| details.schedule.as_deref());
+        if self.type_ == ServiceType::CronJob {
+            match schedule {
+                Some(schedule) => check_schedule(schedule, "serviceDetails.schedule", &mut errors),
+                None => errors.push(ValidationError::new(
+                    "serviceDetails.schedule",
+                    "is required for cron jobs",
+                )),
+            }
+
+            let command = details
+                .and_then(|details| details.env_specific_details.as_ref())
+                .and_then(|commands| commands.start_command.as_deref());
+            if self.image.is_none() && command.is_none_or(str::is_empty) {
+                errors.push(ValidationError::new(
+                    "serviceDetails.envSpecificDetails.startCommand",
+                    "is required for cron jobs",
+                ));
+            }
+        } else if schedule.is_some() {
+            errors.push(ValidationError::new(
+                "serviceDetails.schedule",
+                "only applies to cron jobs",
+            ));
+        }
+
+        if let Some(disk) = details.and_then(|details| details.disk.as_ref()) {
+            if matches!(self.type_, ServiceType::StaticSite | ServiceType::CronJob) {
+                errors.push(ValidationError::new(
+                    "serviceDetails.disk",
+                    format!("isn't supported by {} services", self.type_),
+                ));
+            }
+
+            let num_instances = details.and_then(|details| details.num_instances);
+            if num_instances.is_some_and(|n| n > 1) || self.autoscaling.is_some() {
+                errors.push(ValidationError::new(
+                    "serviceDetails.disk",
+                    "can only be attached to a single instance, without autoscaling",
+                ));
+            }
+
+            for mut error in disk.validate().0 {
+                error.field = format!("serviceDetails.disk.{}", error.field);
+                errors.push(error);
+            }
+        }
+
+        ValidationErrors(errors)
+    }
+}
+
+impl Validate for ServiceUpdate {
+    fn validate(&self) -> ValidationErrors {
+        let mut errors = vec![];
+
+        if let Some(name) = &self.name {
+            check_name(name, "name", true, &mut errors);
+        }
+        if let Some(repo) = self
+            .repo
+            .as_ref()
+            .filter(|repo| !repo.starts_with("https://"))
+        {
+            errors.push(ValidationError::new(
+                "repo",
+                format!("must be an https:// url, found {:?}", repo),
+            ));
+        }
+        if let Some(auto_deploy) = &self.auto_deploy {
+            check_variant(auto_deploy.as_str(), "autoDeploy", TOGGLES, &mut errors);
+        }
+
+        if let Some(details) = &self.service_details {
+            validate_service_details(details, &mut errors);
+
+            if let Some(schedule) = &details.schedule {
+                check_schedule(schedule, "serviceDetails.schedule", &mut errors);
+            }
+            if let Some(disk) = &details.disk {
+                for mut error in disk.validate().0 {
+                    error.field = format!("serviceDetails.disk.{}", error.field);
+                    errors.push(error);
+                }
+            }
+        }
+
+        ValidationErrors(errors)
+    }
+}
+
+impl Validate for Disk {
+    fn validate(&self) -> ValidationErrors {
+        let mut errors = vec![];
+
+        check_name(&self.name, "name", true, &mut errors);
+
+        if !self.mount_path.starts_with('/') {
+            errors.push(ValidationError::new(
+                "mountPath",
+                format!("must be an absolute path, found {:?}", self.mount_path),
+            ));
+        }
+
+        if self.size_gb < 1 {
+            errors.push(ValidationError::new("sizeGB", "must be at least 1"));
+        }
+
+        ValidationErrors(errors)
+    }
+}
+
+impl Validate for PostgresUpdate {
+    fn validate(&self) -> ValidationErrors {
+        let mut errors = vec![];
+
+        if let Some(name) = &self.name {
+            check_name(name, "name", true, &mut errors);
+        }
+        if let Some(plan) = &self.plan {
+            check_one_of(plan, "plan", POSTGRES_PLANS, &mut errors);
+        }
+        if self.diskSizeGB.is_some_and(|size| size < 1) {
+            errors.push(ValidationError::new("diskSizeGB", "must be at least 1"));
+        }
+
+        for (index, block) in self.ipAllowList.iter().flatten().enumerate() {
+            check_cidr(
+                &block.cidrBlock,
+                &format!("ipAllowList[{}].cidrBlock", index),
+                &mut errors,
+            );
+        }
+        for (index, replica) in self.readReplicas.iter().flatten().enumerate() {
+            check_name(
+                &replica.name,
+                &format!("readReplicas[{}].name", index),
+                true,
+                &mut errors,
+            );
+        }
+
+        ValidationErrors(errors)
+    }
+}
+
+impl Validate for RedisUpdate {
+    fn validate(&self) -> ValidationErrors {
+        let mut errors = vec![];
+
+        if let Some(name) = &self.name {
+            check_name(name, "name", true, &mut errors);
+        }
+        if let Some(plan) = &self.plan {
+            check_one_of(plan, "plan", REDIS_PLANS, &mut errors);
+        }
+        if let Some(policy) = &self.maxmemoryPolicy {
+            check_variant(
+                policy.as_str(),
+                "maxmemoryPolicy",
+                MaxmemoryPolicy::VALUES,
+                &mut errors,
+            );
+        }
+
+        for (index, block) in self.ipAllowList.iter().flatten().enumerate() {
+            check_cidr(
+                &block.cidrBlock,
+                &format!("ipAllowList[{}].cidrBlock", index),
+                &mut errors,
+            );
+        }
+
+        ValidationErrors(errors)
+    }
+}
+
+/// Checks shared by [Template] and [ServiceUpdate], the fields depending on the service type aren't.
+fn validate_service_details(details: &ServiceDetails, errors: &mut Vec<ValidationError>) {
+    if let Some(region) = &details.region {
+        check_variant(region.as_str(), "serviceDetails.region", REGIONS, errors);
+    }
+    if let Some(plan) = &details.plan {
+        check_variant(plan.as_str(), "serviceDetails.plan", SERVICE_PLANS, errors);
+    }
+    if let Some(runtime) = &details.runtime {
+        check_variant(runtime.as_str(), "serviceDetails.runtime", RUNTIMES, errors);
+    }
+    if let Some(previews) = &details.pull_request_previews_enabled {
+        check_variant(
+            previews.as_str(),
+            "serviceDetails.pullRequestPreviewsEnabled",
+            TOGGLES,
+            errors,
+        );
+    }
+    if let Some(commands) = &details.env_specific_details {
+        let docker_fields = [
+            ("dockerCommand", &commands.docker_command),
+            ("dockerfilePath", &commands.dockerfile_path),
+            ("dockerContext", &commands.docker_context),
+            ("registryCredentialId", &commands.registry_credential_id),
+        ];
+        let docker = matches!(details.runtime, None | Some(Runtime::Docker));
+
+        for (field, value) in docker_fields {
+            if value.is_some() && !docker {
+                errors.push(ValidationError::new(
+                    format!("serviceDetails.envSpecificDetails.{}", field),
+                    "only applies to the docker runtime",
+                ));
+            }
+        }
+    }
+    if let Some(num_instances) = details.num_instances {
+        if num_instances < 1 {
+            errors.push(ValidationError::new(
+                "serviceDetails.numInstances",
+                format!("must be at least 1, found {}", num_instances),
+            ));
+        }
+    }
+}
+
+fn validate_database(database: &DatabaseConf, path: &str, errors: &mut Vec<ValidationError>) {
+    if database.plan.is_empty() {
+        errors.push(ValidationError::new(
+            format!("{}.plan", path),
+            "is required",
+        ));
+    } else {
+        check_one_of(
+            &database.plan,
+            &format!("{}.plan", path),
+            POSTGRES_PLANS,
+            errors,
+        );
+    }
+
+    if database.version.is_empty() {
+        errors.push(ValidationError::new(
+            format!("{}.version", path),
+            "is required",
+        ));
+    } else {
+        check_one_of(
+            &database.version,
+            &format!("{}.version", path),
+            POSTGRES_VERSIONS,
+            errors,
+        );
+    }
+
+    if let Some(region) = &database.region {
+        check_variant(
+            region.as_str(),
+            &format!("{}.region", path),
+            REGIONS,
+            errors,
+        );
+    }
+
+    // Blank names are generated, see [Conf::populate_blank_values].
+    if let Some(name) = &database.name {
+        check_name(name, &format!("{}.name", path), false, errors);
+    }
+    if let Some(database_name) = &database.databaseName {
+        check_identifier(database_name, &format!("{}.databaseName", path), errors);
+    }
+    if let Some(database_user) = &database.databaseUser {
+        check_identifier(database_user, &format!("{}.databaseUser", path), errors);
+    }
+
+    for (index, block) in database.cidrBlocks.iter().enumerate() {
+        check_cidr(
+            &block.cidrBlock,
+            &format!("{}.cidrBlocks[{}].cidrBlock", path, index),
+            errors,
+        );
+    }
+}
+
+fn validate_naming(naming: &NamingPolicy, path: &str, errors: &mut Vec<ValidationError>) {
+    let mut template = naming.template.clone();
+    for placeholder in PLACEHOLDERS {
+        template = template.replace(placeholder, "");
+    }
+
+    if naming.template.is_empty() {
+        errors.push(ValidationError::new(
+            format!("{}.template", path),
+            "is required",
+        ));
+    } else if template.contains(['{', '}']) {
+        errors.push(ValidationError::new(
+            format!("{}.template", path),
+            format!(
+                "may only contain the placeholders {:?}, found {:?}",
+                PLACEHOLDERS, naming.template
+            ),
+        ));
+    }
+
+    if naming.max_length == 0 || naming.max_length > MAX_NAME_LENGTH {
+        errors.push(ValidationError::new(
+            format!("{}.maxLength", path),
+            format!(
+                "must be between 1 and {}, found {}",
+                MAX_NAME_LENGTH, naming.max_length
+            ),
+        ));
+    }
+}
+
+fn validate_redis(redis: &CacheConf, path: &str, errors: &mut Vec<ValidationError>) {
+    // A blank plan defaults to "starter".
+    if !redis.plan.is_empty() {
+        check_one_of(&redis.plan, &format!("{}.plan", path), REDIS_PLANS, errors);
+    }
+
+    if let Some(region) = &redis.region {
+        check_variant(
+            region.as_str(),
+            &format!("{}.region", path),
+            REGIONS,
+            errors,
+        );
+    }
+
+    if let Some(policy) = &redis.maxmemoryPolicy {
+        check_variant(
+            policy.as_str(),
+            &format!("{}.maxmemoryPolicy", path),
+            MaxmemoryPolicy::VALUES,
+            errors,
+        );
+    }
+
+    if let Some(name) = &redis.name {
+        check_name(name, &format!("{}.name", path), false, errors);
+    }
+
+    for (index, block) in redis.cidrBlocks.iter().enumerate() {
+        check_cidr(
+            &block.cidrBlock,
+            &format!("{}.cidrBlocks[{}].cidrBlock", path, index),
+            errors,
+        );
+    }
+}
+
+static ALLOW_UNKNOWN_VALUES: AtomicBool = AtomicBool::new(false);
+
+/// Lets `Other` values of typed fields through validation with a warning, i.e a region Render added
+/// after this version of the crate. Off by default, so typos are rejected.
+pub fn allow_unknown_values(allow: bool) {
+    ALLOW_UNKNOWN_VALUES.store(allow, Ordering::Relaxed);
+}
+
+/// Like [check_one_of] for typed values, see [allow_unknown_values]. Blank values are always rejected.
+fn check_variant(value: &str, field: &str, allowed: &[&str], errors: &mut Vec<ValidationError>) {
+    if value.is_empty() || allowed.contains(&value) || !ALLOW_UNKNOWN_VALUES.load(Ordering::Relaxed)
+    {
+        return check_one_of(value, field, allowed, errors);
+    }
+
+    LOGGER!(
+        "[VALIDATION] :: Unknown value, sent as is. -> ",
+        format!("`{}` = {:?}, known values are {:?}", field, value, allowed),
+        LogLevel::WARN
+    );
+}
+
+fn check_one_of(value: &str, field: &str, allowed: &[&str], errors: &mut Vec<ValidationError>) {
+    if !allowed.contains(&value) {
+        errors.push(ValidationError::new(
+            field,
+            format!("must be one of {:?}, found {:?}", allowed, value),
+        ));
+    }
+}
+
+/// Resource names i.e `my-service_1`.
+pub fn check_name(name: &str, field: &str, required: bool, errors: &mut Vec<ValidationError>) {
+    if name.is_empty() {
+        if required {
+            errors.push(ValidationError::new(field, "is required"));
+        }
+        return;
+    }
+
+    if name.len() > MAX_NAME_LENGTH {
+        errors.push(ValidationError::new(
+            field,
+            format!(
+                "must be at most {} characters, found {}",
+                MAX_NAME_LENGTH,
+                name.len()
+            ),
+        ));
+    }
+
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        errors.push(ValidationError::new(
+            field,
+            format!(
+                "may only contain letters, digits, '-' and '_', found {:?}",
+                name
+            ),
+        ));
+    }
+}
+
+/// Postgres identifiers i.e database names and users.
+fn check_identifier(identifier: &str, field: &str, errors: &mut Vec<ValidationError>) {
+    if identifier.is_empty() {
+        return;
+    }
+
+    let starts_with_letter = identifier
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic());
+    let valid_chars = identifier
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+
+    if !starts_with_letter || !valid_chars || identifier.len() > MAX_NAME_LENGTH {
+        errors.push(ValidationError::new(
+            field,
+            format!(
+                "must start with a letter and only contain lowercase letters, digits and '_' (max {} characters), found {:?}",
+                MAX_NAME_LENGTH, identifier
+            ),
+        ));
+    }
+}
+
+/// Cron expressions with 5 fields, i.e `*/15 * * * *` or `0 3 * * MON-FRI`.
+fn check_schedule(schedule: &str, field: &str, errors: &mut Vec<ValidationError>) {
+    let fields: Vec<&str> = schedule.split_whitespace().collect();
+    let valid_fields = fields.iter().all(|field| {
+        field
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '*' | ',' | '-' | '/'))
+    });
+
+    if fields.len() != 5 || !valid_fields {
+        errors.push(ValidationError::new(
+            field,
+            format!(
+                "must be a cron expression with 5 fields i.e \"0 3 * * *\", found {:?}",
+                schedule
+            ),
+        ));
+    }
+}
+
+/// Parses `address/prefix`, i.e `10.0.0.0/16` or `2001:db8::/32`.
+pub fn parse_cidr(cidr: &str) -> Option<(IpAddr, u8)> {
+    let (address, prefix) = cidr.split_once('/')?;
+    let address: IpAddr = address.parse().ok()?;
+    let prefix: u8 = prefix.parse().ok()?;
+    let max_prefix = if address.is_ipv4() { 32 } else { 128 };
+
+    (prefix <= max_prefix).then_some((address, prefix))
+}
+
+fn check_cidr(cidr: &str, field: &str, errors: &mut Vec<ValidationError>) {
+    if parse_cidr(cidr).is_none() {
+        errors.push(ValidationError::new(
+            field,
+            format!("must be a CIDR block i.e \"10.0.0.0/16\", found {:?}", cidr),
+        ));
+    }
+}
+
+/// Finds the line (1-based) a field path is defined on, in TOML, YAML or JSON sources.
+///
+/// Each path segment is searched for after the line of the previous one, and
+/// `[index]` skips that many occurrences of the following key.
+pub fn locate_field(source: &str, field: &str) -> Option<usize> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut cursor = 0;
+    let mut skip = 0;
+
+    for (position, segment) in field.split('.').enumerate() {
+        let (key, index) = match segment.split_once('[') {
+            Some((key, index)) => (key, index.trim_end_matches(']').parse::<usize>().ok()),
+            None => (segment, None),
+        };
+
+        let mut remaining = skip;
+        let found = (cursor..lines.len()).find(|&i| {
+            let line = lines[i].trim_start();
+            if line.starts_with('#') {
+                return false;
+            }
+
+            let matches = is_section(line, key) || has_key(line, key);
+            if matches && remaining > 0 {
+                remaining -= 1;
+                return false;
+            }
+            matches
+        })?;
+
+        cursor = found;
+        skip = index.unwrap_or(0);
+    }
+
+    Some(cursor + 1)
+}
+
+/// Matches `[key]`, `[[key]]` and `[parent.key]` headers.
+fn is_section(line: &str, key: &str) -> bool {
+    let Some(header) = line.strip_prefix('[') else {
+        return false;
+    };
+    let Some((header, _)) = header.trim_start_matches('[').split_once(']') else {
+        return false;
+    };
+
+    header.rsplit('.').next().map(str::trim) == Some(key)
+}
+
+/// Matches `key =`, `key:` and `"key":`, where `key` is not part of a longer name.
+fn has_key(line: &str, key: &str) -> bool {
+    line.match_indices(key).any(|(start, _)| {
+        let before = line[..start].chars().next_back();
+        let after = line[start + key.len()..]
+            .trim_start_matches('"')
+            .trim_start();
+
+        !before.is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+            && (after.starts_with('=') || after.starts_with(':'))
+    })
+}
+
+#[cfg(test)]
+mod validation_tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+[database]
+databaseName = "Orders-DB"
+plan = "startr"
+version = "9"
+enableHighAvailability = false
+cidrBlocks = [
+    { cidrBlock = "10.0.0.0/16", description = "VPC" },
+    { cidrBlock = "10.0.0.0/33", description = "Typo" }
+]
+
+[redis]
+name = "cache"
+plan = "starter"
+region = "mars"
+cidrBlocks = []
+"#;
+
+    #[test]
+    fn test_validate_reports_every_problem_with_lines() {
+        let config: Conf = toml::from_str(SOURCE).unwrap();
+        let errors = config.validate().locate(SOURCE, "orders.conf");
+        let located: Vec<(String, Option<usize>)> = errors
+            .iter()
+            .map(|error| (error.field.clone(), error.line))
+            .collect();
+
+        assert_eq!(
+            located,
+            vec![
+                ("database.plan".to_owned(), Some(4)),
+                ("database.version".to_owned(), Some(5)),
+                ("database.databaseName".to_owned(), Some(3)),
+                ("database.cidrBlocks[1].cidrBlock".to_owned(), Some(9)),
+                ("redis.region".to_owned(), Some(15)),
+            ]
+        );
+        assert!(errors
+            .to_string()
+            .contains("orders.conf:4: `database.plan`"));
+    }
+
+    #[test]
+    fn test_parse_cidr() {
+        assert!(parse_cidr("0.0.0.0/0").is_some());
+        assert!(parse_cidr("2001:db8::/32").is_some());
+        assert!(parse_cidr("10.0.0.0").is_none());
+        assert!(parse_cidr("10.0.0.0/33").is_none());
+        assert!(parse_cidr("10.0.0/8").is_none());
+    }
+}
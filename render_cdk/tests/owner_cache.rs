@@ -0,0 +1,31 @@
+//! Owner resolution against a dedicated mock server.
+//!
+//! Kept in its own test binary, so no other test resolves owners while requests are counted.
+use render_cdk::authentication::owner::Info;
+use render_cdk::testing::prelude::*;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_whoami_requests_owners_once() {
+    let server = shared();
+
+    // Concurrent callers share a single lookup.
+    let callers: Vec<_> = (0..20).map(|_| tokio::spawn(Info::whoami())).collect();
+    for caller in callers {
+        let owner = caller.await.unwrap().unwrap();
+        assert_eq!(owner.id, MOCK_OWNER_ID);
+    }
+    assert_eq!(server.hits("GET", "/owners"), 1);
+
+    // Served from the cache.
+    Info::whoami().await.unwrap();
+    Info::get_owner_id().await;
+    assert_eq!(server.hits("GET", "/owners"), 1);
+
+    // Resolved again, then cached.
+    let refreshed = Info::refresh_owner().await.unwrap();
+    assert_eq!(refreshed.id, MOCK_OWNER_ID);
+    assert_eq!(server.hits("GET", "/owners"), 2);
+
+    Info::whoami().await.unwrap();
+    assert_eq!(server.hits("GET", "/owners"), 2);
+}
@@ -0,0 +1,40 @@
+//! Validation with unknown values allowed.
+//!
+//! Kept in its own test binary, since allowing unknown values applies to the whole process.
+use render_cdk::resource_management::models::prelude::*;
+use render_cdk::utils::validation::{allow_unknown_values, Validate};
+
+#[test]
+fn test_allow_unknown_values() {
+    let template = Template {
+        type_: ServiceType::WebService,
+        name: "api".to_owned(),
+        repo: "https://github.com/lexara-prime-ai/SAMPLE_WEB_SERVICE".to_owned(),
+        service_details: Some(ServiceDetails {
+            region: Some(Region::from("mars")),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let fields = |template: &Template| -> Vec<String> {
+        template
+            .validate()
+            .iter()
+            .map(|e| e.field.clone())
+            .collect()
+    };
+
+    // Rejected by default.
+    assert_eq!(fields(&template), ["serviceDetails.region"]);
+
+    allow_unknown_values(true);
+    assert!(fields(&template).is_empty());
+
+    // Blank values are still rejected.
+    let mut blank = template.clone();
+    blank.service_details.as_mut().unwrap().region = Some(Region::from(""));
+    assert_eq!(fields(&blank), ["serviceDetails.region"]);
+
+    allow_unknown_values(false);
+    assert_eq!(fields(&template), ["serviceDetails.region"]);
+}